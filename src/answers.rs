@@ -0,0 +1,383 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::{Error, Result};
+use crate::input::{bundled_dir, Source, DEFAULT_NAME};
+use crate::parse::{self, Line};
+use crate::solution::Answer;
+
+/// Name of the answers file looked up next to a directory of inputs.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Known answers, keyed by day, input name and part.
+///
+/// They are stored as a subset of TOML, one table per day for its main input and one per other
+/// named input:
+///
+/// ```toml
+/// [day5]
+/// part1 = 6687
+/// part2 = 19851
+///
+/// [day5.example]
+/// part1 = 5
+///
+/// [day13]
+/// part2 = """
+/// #..#
+/// ####"""
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Answers {
+    answers: BTreeMap<(u8, String, u8), Answer>,
+}
+
+/// Outcome of checking an answer against the known one.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Check<'a> {
+    Unknown,
+    Correct,
+    Wrong { expected: &'a Answer },
+}
+
+impl Answers {
+    pub fn get(&self, day: u8, name: &str, part: u8) -> Option<&Answer> {
+        self.answers.get(&(day, name.to_string(), part))
+    }
+
+    pub fn insert(&mut self, day: u8, name: &str, part: u8, answer: Answer) {
+        self.answers.insert((day, name.to_string(), part), answer);
+    }
+
+    pub fn len(&self) -> usize {
+        self.answers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.answers.is_empty()
+    }
+
+    pub fn check(&self, day: u8, name: &str, part: u8, answer: &Answer) -> Check<'_> {
+        match self.get(day, name, part) {
+            None => Check::Unknown,
+            Some(expected) if expected == answer => Check::Correct,
+            Some(expected) => Check::Wrong { expected },
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut answers = Answers::default();
+        let mut table: Option<(u8, &str)> = None;
+
+        let mut lines = parse::lines(text);
+        while let Some(line) = lines.next() {
+            let text = line.text.trim();
+            if text.is_empty() || text.starts_with('#') {
+                continue;
+            }
+
+            if let Some(header) = text.strip_prefix('[') {
+                let header = line.strip_prefix(line.split_once(header, "]")?.0, "day")?;
+                let (day, name) = header.split_once('.').unwrap_or((header, DEFAULT_NAME));
+
+                if name.is_empty() {
+                    return Err(line.error(name, "expected an input name"));
+                }
+
+                table = Some((line.parse(day)?, name));
+                continue;
+            }
+
+            let (key, value) = line.split_once(text, "=")?;
+            let key = key.trim();
+            let value = value.trim();
+
+            let (day, name) =
+                table.ok_or_else(|| line.error(key, "answer outside of a [day<N>] table"))?;
+            let part = match key {
+                "part1" => 1,
+                "part2" => 2,
+                _ => {
+                    return Err(
+                        line.error(key, format!("expected 'part1' or 'part2', found '{}'", key))
+                    )
+                }
+            };
+
+            let answer = if let Some(rest) = value.strip_prefix("\"\"\"") {
+                Answer::Text(parse_multiline(&line, rest, &mut lines)?)
+            } else if value.starts_with('"') {
+                Answer::Text(parse_string(&line, value)?)
+            } else {
+                Answer::Number(line.parse(value)?)
+            };
+
+            if answers
+                .answers
+                .insert((day, name.to_string(), part), answer)
+                .is_some()
+            {
+                return Err(line.error(
+                    key,
+                    format!("duplicate answer for day {} part {}", day, part),
+                ));
+            }
+        }
+
+        Ok(answers)
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = fs::read_to_string(path)
+            .map_err(|e| Error::new(format!("{}: {}", path.display(), e)))?;
+
+        Self::parse(&text).map_err(|e| Error::new(format!("{}: {}", path.display(), e)))
+    }
+
+    /// Answers file matching `source`: the one of a directory of inputs if there is one.
+    /// Single files and stdin have no known answers.
+    pub fn path_for(source: &Source) -> Option<PathBuf> {
+        match source {
+            Source::Dir(dir) => Some(dir.join(ANSWERS_FILE)).filter(|p| p.is_file()),
+            _ => None,
+        }
+    }
+
+    /// Answers of the inputs bundled with the crate.
+    pub fn bundled() -> Result<Self> {
+        Self::load(&bundled_dir().join(ANSWERS_FILE))
+    }
+}
+
+/// Run every bundled input of `puzzle` and check its answers against the recorded ones. Each
+/// input must have at least one recorded answer.
+#[cfg(test)]
+pub fn check_inputs(puzzle: &dyn crate::Puzzle) {
+    let source = Source::default();
+    let answers = Answers::bundled().unwrap();
+    let day = puzzle.day();
+
+    for name in source.names(day) {
+        let input = source.read_named(day, &name).unwrap();
+        let outcomes = puzzle
+            .run(&input, None)
+            .unwrap_or_else(|e| panic!("day {} input {}: {}", day, name, e));
+
+        let mut checked = 0;
+        for outcome in outcomes {
+            match answers.check(day, &name, outcome.part, &outcome.answer) {
+                Check::Unknown => {}
+                Check::Correct => checked += 1,
+                Check::Wrong { expected } => panic!(
+                    "day {} input {} part {}: expected {}, found {}",
+                    day, name, outcome.part, expected, outcome.answer
+                ),
+            }
+        }
+
+        assert!(
+            checked > 0,
+            "day {} input {}: no recorded answer",
+            day,
+            name
+        );
+    }
+}
+
+// Parse a single line basic string, with the usual escapes
+fn parse_string(line: &Line, value: &str) -> Result<String> {
+    let content = value
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .ok_or_else(|| line.error(value, "unterminated string"))?;
+
+    unescape(line, content)
+}
+
+// Parse a multi-line basic string, whose first line starts after the opening quotes. As in
+// TOML, a newline right after them is not part of the string.
+fn parse_multiline<'a>(
+    line: &Line<'a>,
+    first: &'a str,
+    lines: &mut impl Iterator<Item = Line<'a>>,
+) -> Result<String> {
+    let mut content = String::new();
+    let mut current = *line;
+    let mut text = first;
+
+    loop {
+        if let Some(end) = text.find("\"\"\"") {
+            content += &unescape(&current, &text[..end])?;
+            return Ok(content);
+        }
+
+        if !(current.number == line.number && text.is_empty()) {
+            content += &unescape(&current, text)?;
+            content.push('\n');
+        }
+
+        current = lines
+            .next()
+            .ok_or_else(|| line.error(first, "unterminated string"))?;
+        text = current.text;
+    }
+}
+
+fn unescape(line: &Line, text: &str) -> Result<String> {
+    let mut out = String::new();
+    let mut chars = text.char_indices();
+
+    while let Some((offset, c)) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+
+        match chars.next().map(|(_, c)| c) {
+            Some('n') => out.push('\n'),
+            Some('t') => out.push('\t'),
+            Some('"') => out.push('"'),
+            Some('\\') => out.push('\\'),
+            _ => return Err(line.error(&text[offset..], "invalid escape sequence")),
+        }
+    }
+
+    Ok(out)
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut current_table = None;
+
+        for ((day, name, part), answer) in &self.answers {
+            if current_table != Some((day, name)) {
+                if current_table.is_some() {
+                    writeln!(f)?;
+                }
+                if name == DEFAULT_NAME {
+                    writeln!(f, "[day{}]", day)?;
+                } else {
+                    writeln!(f, "[day{}.{}]", day, name)?;
+                }
+                current_table = Some((day, name));
+            }
+
+            match answer {
+                Answer::Number(n) => writeln!(f, "part{} = {}", part, n)?,
+                Answer::Text(s) if s.contains('\n') => {
+                    writeln!(f, "part{} = \"\"\"\n{}\"\"\"", part, escape(s))?
+                }
+                Answer::Text(s) => writeln!(f, "part{} = \"{}\"", part, escape(s))?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    const ANSWERS: &str = r#"
+# Comment
+[day5]
+part1 = 6687
+part2 = 19851
+
+[day5.example]
+part1 = 5
+
+[day13]
+part1 = 712
+part2 = """
+#..#
+####"""
+
+[day30]
+part1 = "a \"quoted\" text"
+"#;
+
+    #[test]
+    fn answers_parse() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+
+        assert_eq!(answers.len(), 6);
+        assert_eq!(answers.get(5, "input", 2), Some(&Answer::Number(19851)));
+        assert_eq!(answers.get(5, "example", 1), Some(&Answer::Number(5)));
+        assert_eq!(answers.get(5, "example", 2), None);
+        assert_eq!(
+            answers.get(13, "input", 2),
+            Some(&Answer::from("#..#\n####".to_string()))
+        );
+        assert_eq!(
+            answers.get(30, "input", 1),
+            Some(&Answer::from("a \"quoted\" text".to_string()))
+        );
+
+        assert_eq!(
+            answers.check(5, "input", 1, &Answer::Number(6687)),
+            Check::Correct
+        );
+        assert_eq!(
+            answers.check(5, "input", 1, &Answer::Number(1)),
+            Check::Wrong {
+                expected: &Answer::Number(6687)
+            }
+        );
+        assert_eq!(
+            answers.check(6, "input", 1, &Answer::Number(1)),
+            Check::Unknown
+        );
+
+        assert_eq!(Answers::parse(&answers.to_string()), Ok(answers));
+    }
+
+    #[test]
+    fn answers_errors() {
+        let err = |input| Answers::parse(input).unwrap_err().to_string();
+
+        assert_eq!(
+            err("part1 = 1"),
+            "line 1, column 1: answer outside of a [day<N>] table"
+        );
+        assert_eq!(
+            err("[day1]\npart3 = 1"),
+            "line 2, column 1: expected 'part1' or 'part2', found 'part3'"
+        );
+        assert_eq!(
+            err("[day1]\npart1 = 1\npart1 = 2"),
+            "line 3, column 1: duplicate answer for day 1 part 1"
+        );
+        assert_eq!(err("[dya1]"), "line 1, column 2: expected 'day'");
+        assert_eq!(err("[day1.]"), "line 1, column 7: expected an input name");
+        assert_eq!(
+            err("[day1]\npart2 = \"\"\"\n#.#"),
+            "line 2, column 12: unterminated string"
+        );
+    }
+
+    #[test]
+    fn answers_bundled() {
+        let answers = Answers::bundled().unwrap();
+
+        // Every bundled input has a known answer, whatever its name
+        for puzzle in registry::DAYS {
+            for name in Source::default().names(puzzle.day()) {
+                assert!(
+                    answers.get(puzzle.day(), &name, 1).is_some(),
+                    "day {} input {}",
+                    puzzle.day(),
+                    name
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,771 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::{Error, Result};
+use crate::grid::Grid;
+use crate::pixmap::Pixmap;
+
+const LIT_COLOR: [u8; 3] = [255, 255, 255];
+const DARK_COLOR: [u8; 3] = [16, 24, 48];
+
+/// Bit `x` of each byte moved to bit `3 * (7 - x)`, to interleave the bits of 3 rows.
+const SPREAD: [u32; 256] = {
+    let mut table = [0; 256];
+
+    let mut byte = 0;
+    while byte < 256 {
+        let mut x = 0;
+        while x < 8 {
+            table[byte] |= ((byte as u32 >> x) & 1) << (3 * (7 - x));
+            x += 1;
+        }
+        byte += 1;
+    }
+
+    table
+};
+
+/// Infinite image: the pixels of a `width` x `height` area, stored as one bitmask per row with
+/// bit `x` for column `x`, every other pixel being `background`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    /// Number of words of a row.
+    words: usize,
+    rows: Vec<u64>,
+    background: bool,
+    /// Position of the top left stored pixel in the image.
+    origin: (i64, i64),
+}
+
+// Mask of the bits of word `i` standing for columns `start..end`
+fn columns_mask(i: usize, start: usize, end: usize) -> u64 {
+    let (lo, hi) = (i * 64, i * 64 + 64);
+    let (start, end) = (start.clamp(lo, hi) - lo, end.clamp(lo, hi) - lo);
+
+    match end - start {
+        0 => 0,
+        64 => !0,
+        n => ((1 << n) - 1) << start,
+    }
+}
+
+impl Image {
+    pub fn from_fn(
+        width: usize,
+        height: usize,
+        background: bool,
+        mut pixel: impl FnMut(usize, usize) -> bool,
+    ) -> Self {
+        let words = width.div_ceil(64);
+        let mut rows = vec![0; words * height];
+
+        for y in 0..height {
+            for x in 0..width {
+                rows[y * words + x / 64] |= (pixel(x, y) as u64) << (x % 64);
+            }
+        }
+
+        Self {
+            width,
+            height,
+            words,
+            rows,
+            background,
+            origin: (0, 0),
+        }
+    }
+
+    pub fn from_grid(grid: &Grid<bool>) -> Self {
+        Self::from_fn(grid.width(), grid.height(), false, |x, y| grid[(x, y)])
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// State of the pixels outside of the stored area.
+    pub fn background(&self) -> bool {
+        self.background
+    }
+
+    pub fn origin(&self) -> (i64, i64) {
+        self.origin
+    }
+
+    /// Pixel at `(x, y)` of the stored area.
+    fn pixel(&self, x: usize, y: usize) -> bool {
+        (self.rows[y * self.words + x / 64] >> (x % 64)) & 1 == 1
+    }
+
+    /// Pixel anywhere in the image.
+    pub fn get(&self, (x, y): (i64, i64)) -> bool {
+        let (x, y) = (x - self.origin.0, y - self.origin.1);
+
+        if (0..self.width as i64).contains(&x) && (0..self.height as i64).contains(&y) {
+            self.pixel(x as usize, y as usize)
+        } else {
+            self.background
+        }
+    }
+
+    /// Number of lit pixels, unless the background is lit.
+    pub fn lit(&self) -> Option<usize> {
+        (!self.background).then(|| self.rows.iter().map(|w| w.count_ones() as usize).sum())
+    }
+
+    /// Rows of the image with `margin` more background pixels on each side.
+    fn padded(&self, margin: usize) -> (Vec<u64>, usize) {
+        let width = self.width + 2 * margin;
+        let words = width.div_ceil(64);
+        let fill = if self.background { !0 } else { 0 };
+
+        let mut rows = vec![fill; words * (self.height + 2 * margin)];
+        for y in 0..self.height {
+            let src = &self.rows[y * self.words..(y + 1) * self.words];
+            let dst = &mut rows[(y + margin) * words..(y + margin + 1) * words];
+
+            for (i, cell) in dst.iter_mut().enumerate() {
+                let shifted = src.get(i).map_or(0, |w| w << margin)
+                    | i.checked_sub(1)
+                        .and_then(|j| src.get(j))
+                        .map_or(0, |w| w >> (64 - margin));
+                let inside = columns_mask(i, margin, self.width + margin);
+
+                *cell = (fill & !inside) | shifted;
+            }
+        }
+
+        (rows, words)
+    }
+
+    /// Drop the rows and columns on the sides which only hold background pixels.
+    pub fn crop(&mut self) {
+        let fill = if self.background { !0 } else { 0 };
+
+        // Columns holding a pixel differing from the background, and the rows as well
+        let mut columns = vec![0; self.words];
+        let mut rows = vec![];
+        for y in 0..self.height {
+            let mut differs = false;
+
+            for (i, column) in columns.iter_mut().enumerate() {
+                let diff = (self.rows[y * self.words + i] ^ fill) & columns_mask(i, 0, self.width);
+                *column |= diff;
+                differs |= diff != 0;
+            }
+            if differs {
+                rows.push(y);
+            }
+        }
+
+        let bits = || columns.iter().enumerate().filter(|(_, &w)| w != 0);
+        let (top, bottom, left, right) =
+            match (rows.first(), rows.last(), bits().next(), bits().next_back()) {
+                (Some(&top), Some(&bottom), Some((i, w)), Some((j, v))) => (
+                    top,
+                    bottom,
+                    i * 64 + w.trailing_zeros() as usize,
+                    j * 64 + 63 - v.leading_zeros() as usize,
+                ),
+                _ => {
+                    *self = Self {
+                        origin: self.origin,
+                        ..Self::from_fn(0, 0, self.background, |_, _| false)
+                    };
+                    return;
+                }
+            };
+
+        if (top, left) == (0, 0) && (bottom + 1, right + 1) == (self.height, self.width) {
+            return;
+        }
+
+        let mut image = Self::from_fn(
+            right + 1 - left,
+            bottom + 1 - top,
+            self.background,
+            |x, y| self.pixel(x + left, y + top),
+        );
+        image.origin = (self.origin.0 + left as i64, self.origin.1 + top as i64);
+
+        *self = image;
+    }
+
+    /// Area of the image of `width` x `height` pixels from `origin`.
+    pub fn window(&self, origin: (i64, i64), width: usize, height: usize) -> Self {
+        let mut image = Self::from_fn(width, height, self.background, |x, y| {
+            self.get((origin.0 + x as i64, origin.1 + y as i64))
+        });
+        image.origin = origin;
+
+        image
+    }
+
+    /// Binary PBM (`P4`) file of the stored area, lit pixels being black.
+    pub fn to_pbm(&self) -> Vec<u8> {
+        let mut out = format!("P4\n{} {}\n", self.width, self.height).into_bytes();
+
+        // Rows are padded to whole bytes, their first pixel being the most significant bit
+        for y in 0..self.height {
+            for x in (0..self.width).step_by(8) {
+                let byte = (0..8)
+                    .filter(|i| x + i < self.width && self.pixel(x + i, y))
+                    .fold(0, |byte, i| byte | 0x80 >> i);
+                out.push(byte);
+            }
+        }
+
+        out
+    }
+
+    /// Plain (`P1`) or binary (`P4`) PBM file, lit pixels being black. Pixels outside of the
+    /// file are dark.
+    pub fn from_pbm(data: &[u8]) -> Result<Self> {
+        let mut pos = 0;
+
+        // Header fields are separated by whitespace and comments
+        let mut field = |name: &str| {
+            loop {
+                match data.get(pos) {
+                    Some(c) if c.is_ascii_whitespace() => pos += 1,
+                    Some(b'#') => {
+                        while data.get(pos).is_some_and(|&c| c != b'\n') {
+                            pos += 1;
+                        }
+                    }
+                    _ => break,
+                }
+            }
+
+            let start = pos;
+            while data.get(pos).is_some_and(|c| !c.is_ascii_whitespace()) {
+                pos += 1;
+            }
+
+            let text = String::from_utf8_lossy(&data[start..pos]).into_owned();
+            if text.is_empty() {
+                Err(Error::new(format!("PBM file: missing {}", name)))
+            } else {
+                Ok(text)
+            }
+        };
+
+        let magic = field("magic number")?;
+        let mut size = |name| {
+            let text = field(name)?;
+            text.parse::<usize>()
+                .map_err(|_| Error::new(format!("PBM file: invalid {} '{}'", name, text)))
+        };
+        let (width, height) = (size("width")?, size("height")?);
+
+        // Sizes come from the file, so check them against its data before allocating anything
+        let count = width.checked_mul(height).ok_or_else(|| {
+            Error::new(format!(
+                "PBM file: {}x{} pixels is too large",
+                width, height
+            ))
+        })?;
+        if count == 0 {
+            return Ok(Self::from_fn(0, 0, false, |_, _| false));
+        }
+
+        let truncated = || Error::new("PBM file: truncated pixel data");
+        let trailing = || Error::new("PBM file: unexpected data after the pixels");
+        match magic.as_str() {
+            "P1" => {
+                let mut digits = data[pos..].iter().filter(|c| !c.is_ascii_whitespace());
+                let pixels: Vec<bool> = digits
+                    .by_ref()
+                    .take(count)
+                    .map(|&c| match c {
+                        b'0' => Ok(false),
+                        b'1' => Ok(true),
+                        _ => Err(Error::new(format!(
+                            "PBM file: expected '0' or '1', found '{}'",
+                            c as char
+                        ))),
+                    })
+                    .collect::<Result<_>>()?;
+                if pixels.len() < count {
+                    return Err(truncated());
+                }
+                if digits.next().is_some() {
+                    return Err(trailing());
+                }
+
+                Ok(Self::from_fn(width, height, false, |x, y| {
+                    pixels[y * width + x]
+                }))
+            }
+            "P4" => {
+                // A single whitespace separates the header from the pixels
+                let pixels = &data[(pos + 1).min(data.len())..];
+                let stride = width.div_ceil(8);
+                match pixels.len().cmp(&(stride * height)) {
+                    Ordering::Less => return Err(truncated()),
+                    Ordering::Greater => return Err(trailing()),
+                    Ordering::Equal => {}
+                }
+
+                Ok(Self::from_fn(width, height, false, |x, y| {
+                    pixels[y * stride + x / 8] & (0x80 >> (x % 8)) != 0
+                }))
+            }
+            _ => Err(Error::new(format!(
+                "PBM file: expected 'P1' or 'P4', found '{}'",
+                magic
+            ))),
+        }
+    }
+
+    /// Picture of the stored area, lit pixels being white.
+    pub fn to_pixmap(&self) -> Pixmap {
+        Pixmap::from_fn(self.width, self.height, |x, y| {
+            if self.pixel(x, y) {
+                LIT_COLOR
+            } else {
+                DARK_COLOR
+            }
+        })
+    }
+}
+
+impl fmt::Display for Image {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in 0..self.height {
+            if y > 0 {
+                writeln!(f)?;
+            }
+
+            for x in 0..self.width {
+                write!(f, "{}", if self.pixel(x, y) { '#' } else { '.' })?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Next state of a cell, from the cells of the square of its neighbourhood.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Rule {
+    /// Next state for each index read from the square, row by row, the top left cell being
+    /// the most significant bit.
+    Table(Vec<bool>),
+    /// Rule of the Game of Life family: a dark cell is born when its number of lit neighbours
+    /// has its bit set in `birth`, a lit one survives when it has its bit set in `survive`.
+    LifeLike { birth: u64, survive: u64 },
+}
+
+impl Rule {
+    /// Next state of the cell in the middle of a `size` x `size` square read as `index`.
+    fn next(&self, index: u64, size: usize) -> bool {
+        match self {
+            Rule::Table(table) => table[index as usize],
+            Rule::LifeLike { birth, survive } => {
+                let center = size * size / 2;
+                let lit = (index >> center) & 1 == 1;
+                let neighbours = index.count_ones() - lit as u32;
+
+                let counts = if lit { survive } else { birth };
+                (counts >> neighbours) & 1 == 1
+            }
+        }
+    }
+}
+
+impl FromStr for Rule {
+    type Err = Error;
+
+    /// Parse a life-like rule written like `B3/S23`, with a digit per number of neighbours, or
+    /// like `B10,12-14/S2-4` with comma separated counts and ranges, for larger squares.
+    fn from_str(s: &str) -> Result<Self> {
+        let count = |n: &str| match n.parse::<u32>() {
+            Ok(n) if n < 64 => Ok(n),
+            _ => Err(Error::new(format!(
+                "expected a number of neighbours below 64, found '{}'",
+                n
+            ))),
+        };
+
+        let counts = |part: &str, prefix: char| {
+            let digits = part.strip_prefix(prefix).ok_or_else(|| {
+                Error::new(format!(
+                    "expected '{}' followed by digits, found '{}'",
+                    prefix, part
+                ))
+            })?;
+
+            if !digits.contains([',', '-']) {
+                return digits
+                    .chars()
+                    .try_fold(0u64, |mask, c| match c.to_digit(10) {
+                        Some(n) => Ok(mask | 1 << n),
+                        None => Err(Error::new(format!("expected a digit, found '{}'", c))),
+                    });
+            }
+
+            digits.split(',').try_fold(0u64, |mask, item| {
+                let (low, high) = match item.split_once('-') {
+                    Some((low, high)) => (count(low)?, count(high)?),
+                    None => (count(item)?, count(item)?),
+                };
+
+                if low > high {
+                    return Err(Error::new(format!("empty range '{}'", item)));
+                }
+
+                Ok(mask | (low..=high).fold(0, |mask, n| mask | 1 << n))
+            })
+        };
+
+        match s.split_once('/') {
+            Some((birth, survive)) => Ok(Rule::LifeLike {
+                birth: counts(birth, 'B')?,
+                survive: counts(survive, 'S')?,
+            }),
+            None => Err(Error::new(format!(
+                "expected 'B<DIGITS>/S<DIGITS>', found '{}'",
+                s
+            ))),
+        }
+    }
+}
+
+/// Cellular automaton updating every cell of an [`Image`] at once, from the square of cells
+/// within `radius` of it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Automaton {
+    radius: usize,
+    rule: Rule,
+    /// Table of [`square_pairs`] for a 3x3 square, built once as it is used at every step.
+    pairs: Vec<u64>,
+}
+
+impl Automaton {
+    pub const MAX_RADIUS: usize = 3;
+    /// Largest radius of a [`Rule::Table`], whose 2^25 entries for a 5x5 square already take
+    /// 32 MiB.
+    pub const MAX_TABLE_RADIUS: usize = 2;
+
+    pub fn new(radius: usize, rule: Rule) -> Result<Self> {
+        if !(1..=Self::MAX_RADIUS).contains(&radius) {
+            return Err(Error::new(format!(
+                "radius must be between 1 and {}, found {}",
+                Self::MAX_RADIUS,
+                radius
+            )));
+        }
+
+        let size = 2 * radius + 1;
+        if let Rule::Table(table) = &rule {
+            if radius > Self::MAX_TABLE_RADIUS {
+                return Err(Error::new(format!(
+                    "radius of a rule table must be at most {}, found {}",
+                    Self::MAX_TABLE_RADIUS,
+                    radius
+                )));
+            }
+
+            if table.len() as u64 != 1 << (size * size) {
+                return Err(Error::new(format!(
+                    "expected {} entries in the rule table of a {}x{} square, found {}",
+                    1u64 << (size * size),
+                    size,
+                    size,
+                    table.len()
+                )));
+            }
+        }
+
+        Ok(Self::with_rule(radius, rule))
+    }
+
+    fn with_rule(radius: usize, rule: Rule) -> Self {
+        let pairs = if radius == 1 {
+            square_pairs(&rule)
+        } else {
+            vec![]
+        };
+
+        Self {
+            radius,
+            rule,
+            pairs,
+        }
+    }
+
+    /// Conway's Game of Life.
+    pub fn life() -> Self {
+        Self::with_rule(
+            1,
+            Rule::LifeLike {
+                birth: 1 << 3,
+                survive: 1 << 2 | 1 << 3,
+            },
+        )
+    }
+
+    pub fn radius(&self) -> usize {
+        self.radius
+    }
+
+    /// Update every cell, the area growing by `radius` cells on each side, then crop it to the
+    /// cells which differ from the background.
+    pub fn step(&self, image: &Image) -> Image {
+        let size = 2 * self.radius + 1;
+
+        let mut next = if size == 3 {
+            step_square(image, &self.pairs)
+        } else {
+            self.step_any(image)
+        };
+
+        let all = if image.background {
+            (1 << (size * size)) - 1
+        } else {
+            0
+        };
+        next.background = self.rule.next(all, size);
+        next.origin = (
+            image.origin.0 - self.radius as i64,
+            image.origin.1 - self.radius as i64,
+        );
+        next.crop();
+
+        next
+    }
+
+    /// Update the cells one at a time, sliding the square along each row.
+    fn step_any(&self, image: &Image) -> Image {
+        let size = 2 * self.radius + 1;
+        let (padded, padded_words) = image.padded(2 * self.radius);
+        let bit = |y: usize, x: usize| (padded[y * padded_words + x / 64] >> (x % 64)) & 1;
+
+        // Sliding right drops the first column of the square, the highest bit of each row
+        let kept = (0..size).fold(0, |mask, row| {
+            mask | ((1 << (size - 1)) - 1) << (row * size + 1)
+        });
+
+        let mut index = 0;
+        Image::from_fn(
+            image.width + 2 * self.radius,
+            image.height + 2 * self.radius,
+            false,
+            |x, y| {
+                // Cells are read row by row, so only the first one of a row reads a whole square
+                let columns = if x == 0 {
+                    0..size
+                } else {
+                    x + size - 1..x + size
+                };
+                for column in columns {
+                    let cells = (0..size).fold(0, |cells, row| {
+                        cells | bit(y + row, column) << ((size - 1 - row) * size)
+                    });
+                    index = ((index << 1) & kept) | cells;
+                }
+
+                self.rule.next(index, size)
+            },
+        )
+    }
+}
+
+/// Next states of 2 cells side by side, from the 4 columns of the 3x3 squares around them.
+fn square_pairs(rule: &Rule) -> Vec<u64> {
+    // Index the rule by the 3 bits of each column of the square instead, the top cell first,
+    // so that sliding the square right only shifts the index
+    let columns: Vec<u64> = (0..512)
+        .map(|index| {
+            let row_major = (0..9).fold(0, |acc, bit| {
+                let (column, row) = (bit / 3, bit % 3);
+                acc | (index >> (8 - bit) & 1) << (8 - (row * 3 + column))
+            });
+            rule.next(row_major, 3) as u64
+        })
+        .collect();
+
+    (0..4096)
+        .map(|index| columns[index >> 3] | columns[index & 0x1ff] << 1)
+        .collect()
+}
+
+/// Update the cells of 3x3 squares from the table built by [`square_pairs`].
+fn step_square(image: &Image, pairs: &[u64]) -> Image {
+    let (padded, padded_words) = image.padded(2);
+    let width = image.width + 2;
+    let words = width.div_ceil(64);
+    let mut rows = vec![0; words * (image.height + 2)];
+
+    // Column codes of 8 columns from a byte of each row, the first column highest
+    let columns = |w: [u64; 3], shift: usize| {
+        let spread = |w: u64| SPREAD[(w >> shift) as usize & 0xff];
+        (spread(w[0]) << 2 | spread(w[1]) << 1 | spread(w[2])) as usize
+    };
+
+    // Output pixel (x, y) reads the square of padded pixels from (x, y) to (x + 2, y + 2)
+    for y in 0..image.height + 2 {
+        let padded = |r: usize, i: usize| {
+            let row = &padded[(y + r) * padded_words..(y + r + 1) * padded_words];
+            row.get(i).copied().unwrap_or(0)
+        };
+
+        for i in 0..words {
+            // Start with the first two columns of the square, then slide it 2 columns at a time
+            let mut index = columns([0, 1, 2].map(|r| padded(r, i) & 3), 0) >> 18;
+            let next = [0, 1, 2].map(|r| (padded(r, i) >> 2) | (padded(r, i + 1) << 62));
+
+            let mut word = 0;
+            for byte in 0..8 {
+                let codes = columns(next, byte * 8);
+
+                for pair in 0..4 {
+                    index = ((index << 6) & 0xfff) | (codes >> (18 - pair * 6)) & 0x3f;
+                    word |= pairs[index] << (byte * 8 + pair * 2);
+                }
+            }
+
+            rows[y * words + i] = word & columns_mask(i, 0, width);
+        }
+    }
+
+    Image {
+        width,
+        height: image.height + 2,
+        words,
+        rows,
+        background: false,
+        origin: image.origin,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Lcg;
+
+    // Next image computed cell by cell from the previous one, including a ring of background
+    // around the stored area
+    fn check_step(automaton: &Automaton, image: &Image, next: &Image) {
+        let (left, top) = image.origin();
+        let radius = automaton.radius() as i64;
+        let size = 2 * automaton.radius() + 1;
+
+        for y in top - radius - 2..top + image.height() as i64 + radius + 2 {
+            for x in left - radius - 2..left + image.width() as i64 + radius + 2 {
+                let index = (-radius..=radius)
+                    .flat_map(|dy| (-radius..=radius).map(move |dx| (x + dx, y + dy)))
+                    .fold(0, |index, pos| (index << 1) | image.get(pos) as u64);
+
+                assert_eq!(next.get((x, y)), automaton.rule.next(index, size));
+            }
+        }
+    }
+
+    #[test]
+    fn automaton_step() {
+        let mut random = Lcg::new(24);
+        let mut table = |radius: usize| {
+            let size = 2 * radius + 1;
+            let table = (0..1 << (size * size)).map(|_| random.bool()).collect();
+            Automaton::new(radius, Rule::Table(table)).unwrap()
+        };
+
+        let automata = [
+            table(1),
+            table(2),
+            Automaton::new(2, "B36/S1357".parse().unwrap()).unwrap(),
+            Automaton::new(3, "B0/S8".parse().unwrap()).unwrap(),
+            Automaton::new(3, "B10-14,30/S2-4,20".parse().unwrap()).unwrap(),
+            Automaton::life(),
+        ];
+
+        for automaton in &automata {
+            for (width, height) in [
+                (0, 0),
+                (1, 1),
+                (3, 2),
+                (5, 7),
+                (61, 5),
+                (62, 3),
+                (63, 4),
+                (130, 2),
+            ] {
+                let mut image = Image::from_fn(width, height, random.bool(), |_, _| random.bool());
+
+                for _ in 0..3 {
+                    let next = automaton.step(&image);
+                    check_step(automaton, &image, &next);
+                    image = next;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn automaton_life() {
+        let life = Automaton::life();
+
+        // A blinker flips between a row and a column
+        let blinker = Image::from_fn(3, 1, false, |_, _| true);
+        let flipped = life.step(&blinker);
+        assert_eq!(flipped.to_string(), "#\n#\n#");
+        assert_eq!(flipped.origin(), (1, -1));
+        assert_eq!(life.step(&flipped), blinker);
+
+        // A glider moves one cell diagonally every 4 generations
+        let glider = Image::from_fn(3, 3, false, |x, y| [1, 5, 6, 7, 8].contains(&(y * 3 + x)));
+        let mut image = glider.clone();
+        for _ in 0..4 {
+            image = life.step(&image);
+        }
+        assert_eq!(image.to_string(), glider.to_string());
+        assert_eq!(image.origin(), (1, 1));
+
+        let err = |s: &str| s.parse::<Rule>().unwrap_err().to_string();
+        assert_eq!(
+            err("B3S23"),
+            "expected 'B<DIGITS>/S<DIGITS>', found 'B3S23'"
+        );
+        assert_eq!(err("B3/23"), "expected 'S' followed by digits, found '23'");
+        assert_eq!(err("B3/Sx"), "expected a digit, found 'x'");
+        assert_eq!(
+            err("B3/S2,x"),
+            "expected a number of neighbours below 64, found 'x'"
+        );
+        assert_eq!(
+            err("B64-65/S2"),
+            "expected a number of neighbours below 64, found '64'"
+        );
+        assert_eq!(err("B3/S4-2"), "empty range '4-2'");
+        assert_eq!(
+            "B10,12-14/S2-4,48".parse(),
+            Ok(Rule::LifeLike {
+                birth: 1 << 10 | 1 << 12 | 1 << 13 | 1 << 14,
+                survive: 1 << 2 | 1 << 3 | 1 << 4 | 1 << 48,
+            })
+        );
+        assert_eq!("B3/S23".parse(), Ok(Automaton::life().rule));
+        assert_eq!(
+            Automaton::new(3, Rule::Table(vec![]))
+                .unwrap_err()
+                .to_string(),
+            "radius of a rule table must be at most 2, found 3"
+        );
+        assert_eq!(
+            Automaton::new(1, Rule::Table(vec![false; 4]))
+                .unwrap_err()
+                .to_string(),
+            "expected 512 entries in the rule table of a 3x3 square, found 4"
+        );
+        assert!(Automaton::new(0, Automaton::life().rule).is_err());
+    }
+}
@@ -0,0 +1,269 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::hint;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use crate::error::{Error, Result};
+use crate::parse;
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+
+/// Global allocator counting the allocations made by the process, so benchmarks can report
+/// them. Binaries opt in with `#[global_allocator]`; without it every count is 0.
+pub struct CountingAlloc;
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+/// Number of allocations made so far.
+pub fn allocations() -> u64 {
+    ALLOCATIONS.load(Ordering::Relaxed)
+}
+
+/// Step of a puzzle being measured.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Stage::Parse => "parse",
+            Stage::Part1 => "part1",
+            Stage::Part2 => "part2",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+impl FromStr for Stage {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "parse" => Ok(Stage::Parse),
+            "part1" => Ok(Stage::Part1),
+            "part2" => Ok(Stage::Part2),
+            _ => Err(Error::new(format!(
+                "expected 'parse', 'part1' or 'part2', found '{}'",
+                s
+            ))),
+        }
+    }
+}
+
+/// Summary of the runs of one stage.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+    /// Median number of allocations of a run.
+    pub allocations: u64,
+}
+
+/// Run `f` `runs` times, at least once, and return its last result with the stats of the runs.
+pub fn measure<T>(runs: usize, mut f: impl FnMut() -> Result<T>) -> Result<(T, Stats)> {
+    let runs = runs.max(1);
+
+    let mut durations = Vec::with_capacity(runs);
+    let mut counts = Vec::with_capacity(runs);
+    let mut last = None;
+
+    for _ in 0..runs {
+        // Drop the previous result outside of the measure
+        drop(last.take());
+
+        let before = allocations();
+        let start = Instant::now();
+        let value = hint::black_box(f()?);
+        durations.push(start.elapsed());
+        counts.push(allocations() - before);
+
+        last = Some(value);
+    }
+
+    durations.sort_unstable();
+    counts.sort_unstable();
+
+    let stats = Stats {
+        runs,
+        min: durations[0],
+        median: durations[runs / 2],
+        max: durations[runs - 1],
+        allocations: counts[runs / 2],
+    };
+
+    Ok((last.unwrap(), stats))
+}
+
+/// Stats of every stage of a day.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Report {
+    pub day: u8,
+    pub stages: Vec<(Stage, Stats)>,
+}
+
+/// Previous results, keyed by day and stage.
+pub type Baseline = HashMap<(u8, Stage), Stats>;
+
+const BASELINE_HEADER: &str = "# day stage runs min_ns median_ns max_ns allocations";
+
+/// Write `reports` to `path`, one line per day and stage.
+pub fn write_baseline(path: &Path, reports: &[Report]) -> Result<()> {
+    let mut out = String::from(BASELINE_HEADER);
+    out.push('\n');
+
+    for report in reports {
+        for (stage, stats) in &report.stages {
+            out += &format!(
+                "{} {} {} {} {} {} {}\n",
+                report.day,
+                stage,
+                stats.runs,
+                stats.min.as_nanos(),
+                stats.median.as_nanos(),
+                stats.max.as_nanos(),
+                stats.allocations
+            );
+        }
+    }
+
+    fs::write(path, out).map_err(|e| Error::new(format!("{}: {}", path.display(), e)))
+}
+
+pub fn read_baseline(path: &Path) -> Result<Baseline> {
+    let text =
+        fs::read_to_string(path).map_err(|e| Error::new(format!("{}: {}", path.display(), e)))?;
+
+    parse_baseline(&text).map_err(|e| Error::new(format!("{}: {}", path.display(), e)))
+}
+
+fn parse_baseline(text: &str) -> Result<Baseline> {
+    let mut baseline = Baseline::new();
+
+    for line in parse::lines(text) {
+        if line.text.is_empty() || line.text.starts_with('#') {
+            continue;
+        }
+
+        let fields: Vec<&str> = line.text.split(' ').collect();
+        if fields.len() != 7 {
+            return Err(line.error_at(0, format!("expected 7 fields, found {}", fields.len())));
+        }
+
+        let nanos =
+            |field: &str| -> Result<Duration> { Ok(Duration::from_nanos(line.parse(field)?)) };
+        let stage = fields[1]
+            .parse()
+            .map_err(|e: Error| line.error(fields[1], e.message()))?;
+
+        baseline.insert(
+            (line.parse(fields[0])?, stage),
+            Stats {
+                runs: line.parse(fields[2])?,
+                min: nanos(fields[3])?,
+                median: nanos(fields[4])?,
+                max: nanos(fields[5])?,
+                allocations: line.parse(fields[6])?,
+            },
+        );
+    }
+
+    Ok(baseline)
+}
+
+/// Relative change of the median duration from `before` to `after`, in percent.
+pub fn change(before: &Stats, after: &Stats) -> f64 {
+    let before = before.median.as_secs_f64();
+    let after = after.median.as_secs_f64();
+
+    if before == 0.0 {
+        0.0
+    } else {
+        (after - before) / before * 100.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bench_measure() {
+        let mut calls = 0;
+        let (value, stats) = measure(5, || {
+            calls += 1;
+            Ok(calls)
+        })
+        .unwrap();
+
+        assert_eq!((value, calls), (5, 5));
+        assert_eq!(stats.runs, 5);
+        assert!(stats.min <= stats.median && stats.median <= stats.max);
+
+        assert!(measure(3, || Err::<(), _>(Error::new("failed"))).is_err());
+    }
+
+    #[test]
+    fn bench_baseline() {
+        let stats = Stats {
+            runs: 3,
+            min: Duration::from_nanos(10),
+            median: Duration::from_nanos(20),
+            max: Duration::from_nanos(40),
+            allocations: 7,
+        };
+        let reports = [Report {
+            day: 19,
+            stages: vec![(Stage::Parse, stats), (Stage::Part2, stats)],
+        }];
+
+        let path = std::env::temp_dir().join(format!("aoc2021-bench-{}", std::process::id()));
+        write_baseline(&path, &reports).unwrap();
+        let baseline = read_baseline(&path).unwrap();
+        fs::remove_file(path).unwrap();
+
+        assert_eq!(baseline.len(), 2);
+        assert_eq!(baseline[&(19, Stage::Part2)], stats);
+
+        assert_eq!(
+            parse_baseline("1 part3 1 1 1 1 1").unwrap_err().to_string(),
+            "line 1, column 3: expected 'parse', 'part1' or 'part2', found 'part3'"
+        );
+
+        let slower = Stats {
+            median: Duration::from_nanos(30),
+            ..stats
+        };
+        assert!((change(&stats, &slower) - 50.0).abs() < 1e-9);
+    }
+}
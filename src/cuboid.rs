@@ -0,0 +1,254 @@
+use std::fmt;
+
+use crate::geometry::Point3;
+
+/// Inclusive range of coordinates, never empty.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Range {
+    pub lowest: i32,
+    pub highest: i32,
+}
+
+impl Range {
+    /// Range from `lowest` to `highest` included, which must not be reversed.
+    pub const fn new(lowest: i32, highest: i32) -> Self {
+        assert!(lowest <= highest, "reversed range");
+
+        Self { lowest, highest }
+    }
+
+    pub fn count(&self) -> i64 {
+        self.highest as i64 - self.lowest as i64 + 1
+    }
+
+    pub fn contains(&self, value: i32) -> bool {
+        self.lowest <= value && value <= self.highest
+    }
+
+    pub fn intersect(&self, other: &Self) -> Option<Self> {
+        let lowest = self.lowest.max(other.lowest);
+        let highest = self.highest.min(other.highest);
+
+        (lowest <= highest).then(|| Self::new(lowest, highest))
+    }
+
+    /// Parts of the range below and above `other`, if any.
+    fn outside(&self, other: &Self) -> [Option<Self>; 2] {
+        let below = (self.lowest < other.lowest)
+            .then(|| Self::new(self.lowest, self.highest.min(other.lowest - 1)));
+        let above = (self.highest > other.highest)
+            .then(|| Self::new(self.lowest.max(other.highest + 1), self.highest));
+
+        [below, above]
+    }
+}
+
+impl fmt::Display for Range {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}..{}", self.lowest, self.highest)
+    }
+}
+
+/// Box of cubes, its bounds included.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Cuboid {
+    pub x: Range,
+    pub y: Range,
+    pub z: Range,
+}
+
+impl Cuboid {
+    pub const fn new(x: Range, y: Range, z: Range) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Number of cubes, which can exceed an `i64` for the largest cuboids.
+    pub fn volume(&self) -> i128 {
+        self.x.count() as i128 * self.y.count() as i128 * self.z.count() as i128
+    }
+
+    pub fn contains(&self, cube: Point3) -> bool {
+        self.x.contains(cube.x) && self.y.contains(cube.y) && self.z.contains(cube.z)
+    }
+
+    pub fn intersect(&self, other: &Self) -> Option<Self> {
+        Some(Self {
+            x: self.x.intersect(&other.x)?,
+            y: self.y.intersect(&other.y)?,
+            z: self.z.intersect(&other.z)?,
+        })
+    }
+
+    /// Split the cubes not in `other` into at most 6 disjoint cuboids.
+    pub fn subtract(&self, other: &Self) -> Vec<Self> {
+        let common = match self.intersect(other) {
+            Some(common) => common,
+            None => return vec![*self],
+        };
+
+        // Slice off the parts outside of the common cuboid, one axis after the other
+        let mut pieces = vec![];
+        for x in self.x.outside(&common.x).into_iter().flatten() {
+            pieces.push(Self::new(x, self.y, self.z));
+        }
+        for y in self.y.outside(&common.y).into_iter().flatten() {
+            pieces.push(Self::new(common.x, y, self.z));
+        }
+        for z in self.z.outside(&common.z).into_iter().flatten() {
+            pieces.push(Self::new(common.x, common.y, z));
+        }
+
+        pieces
+    }
+}
+
+impl fmt::Display for Cuboid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "x={},y={},z={}", self.x, self.y, self.z)
+    }
+}
+
+/// Set of cubes, stored as disjoint cuboids.
+#[derive(Clone, Debug, Default)]
+pub struct CuboidSet {
+    cuboids: Vec<Cuboid>,
+}
+
+impl CuboidSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add every cube of `cuboid`.
+    pub fn insert(&mut self, cuboid: Cuboid) {
+        self.remove(&cuboid);
+        self.cuboids.push(cuboid);
+    }
+
+    /// Remove every cube of `cuboid`.
+    pub fn remove(&mut self, cuboid: &Cuboid) {
+        self.cuboids = self
+            .cuboids
+            .iter()
+            .flat_map(|c| c.subtract(cuboid))
+            .collect();
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut set = self.clone();
+        for cuboid in &other.cuboids {
+            set.insert(*cuboid);
+        }
+
+        set
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut set = self.clone();
+        for cuboid in &other.cuboids {
+            set.remove(cuboid);
+        }
+
+        set
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        // Intersections of disjoint cuboids are disjoint too
+        let cuboids = self
+            .cuboids
+            .iter()
+            .flat_map(|a| other.cuboids.iter().filter_map(move |b| a.intersect(b)))
+            .collect();
+
+        Self { cuboids }
+    }
+
+    /// Number of cubes.
+    pub fn volume(&self) -> i128 {
+        self.cuboids.iter().map(Cuboid::volume).sum()
+    }
+
+    pub fn contains(&self, cube: Point3) -> bool {
+        self.cuboids.iter().any(|c| c.contains(cube))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cuboids.is_empty()
+    }
+
+    /// Disjoint cuboids covering the set, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = &Cuboid> {
+        self.cuboids.iter()
+    }
+}
+
+impl FromIterator<Cuboid> for CuboidSet {
+    fn from_iter<I: IntoIterator<Item = Cuboid>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for cuboid in iter {
+            set.insert(cuboid);
+        }
+
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cube(lowest: i32, highest: i32) -> Cuboid {
+        let range = Range::new(lowest, highest);
+        Cuboid::new(range, range, range)
+    }
+
+    #[test]
+    fn cuboid_subtract() {
+        let a = cube(0, 9);
+
+        let pieces = a.subtract(&cube(3, 5));
+        assert_eq!(pieces.len(), 6);
+        assert_eq!(pieces.iter().map(Cuboid::volume).sum::<i128>(), 1000 - 27);
+        assert!(pieces.iter().all(|p| !p.contains(Point3::new(4, 4, 4))));
+
+        assert_eq!(a.subtract(&cube(20, 30)), vec![a]);
+        assert!(a.subtract(&cube(-5, 15)).is_empty());
+        assert_eq!(a.to_string(), "x=0..9,y=0..9,z=0..9");
+    }
+
+    #[test]
+    fn cuboid_set() {
+        let a: CuboidSet = [cube(0, 9)].into_iter().collect();
+        let b: CuboidSet = [cube(5, 14)].into_iter().collect();
+
+        assert_eq!(a.union(&b).volume(), 2000 - 125);
+        assert_eq!(a.intersection(&b).volume(), 125);
+        assert_eq!(a.difference(&b).volume(), 1000 - 125);
+        assert!(a.difference(&b).contains(Point3::new(0, 9, 9)));
+        assert!(!a.difference(&b).contains(Point3::new(7, 7, 7)));
+
+        // Pieces stay disjoint
+        let union = a.union(&b);
+        for (i, p) in union.iter().enumerate() {
+            assert!(union.iter().skip(i + 1).all(|q| p.intersect(q).is_none()));
+        }
+
+        assert!(a.intersection(&CuboidSet::new()).is_empty());
+    }
+
+    #[test]
+    fn cuboid_extremes() {
+        let range = Range::new(i32::MIN, i32::MAX);
+        assert_eq!(range.count(), 1 << 32);
+
+        let all = Cuboid::new(range, range, range);
+        assert_eq!(all.volume(), 1 << 96);
+
+        let set: CuboidSet = [all, cube(0, 9)].into_iter().collect();
+        assert_eq!(set.volume(), 1 << 96);
+        assert_eq!(
+            set.difference(&[cube(0, 9)].into_iter().collect()).volume(),
+            (1 << 96) - 1000
+        );
+    }
+}
@@ -0,0 +1,82 @@
+use crate::error::Result;
+use crate::parse;
+use crate::solution::{Answer, Solution};
+
+struct Window {
+    sum: u32,
+    count: u8,
+}
+
+fn parse_input(input: &str) -> Result<Vec<u32>> {
+    parse::lines(input).map(|l| l.parse(l.text)).collect()
+}
+
+fn part1(depths: &[u32]) -> u32 {
+    depths.windows(2).filter(|w| w[1] > w[0]).count() as u32
+}
+
+fn part2(depths: &[u32]) -> u32 {
+    let mut count = 0;
+    let mut prev: Option<u32> = None;
+    let mut windows: Vec<Window> = vec![];
+
+    for &value in depths {
+        // Update existing Windows
+        for win in &mut windows {
+            win.sum += value;
+            win.count += 1;
+        }
+
+        // Add a new Window
+        windows.push(Window {
+            sum: value,
+            count: 1,
+        });
+
+        // Pull first item if required
+        if windows[0].count == 3 {
+            let win = windows.remove(0);
+
+            if let Some(prev) = prev {
+                if win.sum > prev {
+                    count += 1;
+                }
+            }
+
+            prev = Some(win.sum);
+        }
+    }
+
+    count
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Sonar Sweep";
+
+    type Input = Vec<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(depths: &Self::Input) -> Result<Answer> {
+        Ok(part1(depths).into())
+    }
+
+    fn part2(depths: &Self::Input) -> Result<Option<Answer>> {
+        Ok(Some(part2(depths).into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn day1_inputs() {
+        crate::answers::check_inputs(&Day1);
+    }
+}
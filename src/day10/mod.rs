@@ -1,7 +1,8 @@
 use std::collections::HashMap;
-use std::fs;
 
-const INPUT_PATH: &str = "src/day10/input.txt";
+use crate::error::{Error, Result};
+use crate::parse;
+use crate::solution::{Answer, Solution};
 
 enum State {
     Ok,
@@ -31,11 +32,20 @@ fn line_state(l: &str) -> State {
     }
 }
 
-fn part1(input: &str) -> u64 {
+fn parse_input(input: &str) -> Result<Vec<String>> {
+    parse::lines(input)
+        .map(|l| match l.text.find(|c| !"()[]{}<>".contains(c)) {
+            Some(offset) => Err(l.error_at(offset, "expected a delimiter")),
+            None => Ok(l.text.to_string()),
+        })
+        .collect()
+}
+
+fn part1(lines: &[String]) -> u64 {
     let scores_map = HashMap::from([(')', 3), (']', 57), ('}', 1197), ('>', 25137)]);
 
-    input
-        .lines()
+    lines
+        .iter()
         .filter_map(|l| {
             if let State::Corrupted(invalid_char) = line_state(l) {
                 Some(scores_map.get(&invalid_char).unwrap())
@@ -46,11 +56,11 @@ fn part1(input: &str) -> u64 {
         .sum()
 }
 
-fn part2(input: &str) -> u64 {
+fn part2(lines: &[String]) -> Result<u64> {
     let scores_map = HashMap::from([('(', 1), ('[', 2), ('{', 3), ('<', 4)]);
 
-    let mut scores: Vec<_> = input
-        .lines()
+    let mut scores: Vec<_> = lines
+        .iter()
         .filter_map(|l| {
             if let State::Incomplete(stack) = line_state(l) {
                 Some(
@@ -65,20 +75,40 @@ fn part2(input: &str) -> u64 {
         })
         .collect();
 
+    if scores.is_empty() {
+        return Err(Error::new("no incomplete line"));
+    }
+
     scores.sort_unstable();
-    scores[scores.len() / 2]
+    Ok(scores[scores.len() / 2])
 }
 
-fn main() {
-    let input = fs::read_to_string(INPUT_PATH).unwrap();
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Syntax Scoring";
+
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(lines: &Self::Input) -> Result<Answer> {
+        Ok(part1(lines).into())
+    }
+
+    fn part2(lines: &Self::Input) -> Result<Option<Answer>> {
+        Ok(Some(part2(lines)?.into()))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    const INPUT: &'static str = "[({(<(())[]>[[{[]{<()<>>
+    use crate::input::load;
+    const INPUT: &str = "[({(<(())[]>[[{[]{<()<>>
 [(()[<>])]({[<{<<[]>>(
 {([(<{}[<>[]}>{[]{[(<()>
 (((({<>}<{<{<>}{[]{[]{}
@@ -91,13 +121,21 @@ mod tests {
 
     #[test]
     fn day10_part1() {
-        assert_eq!(part1(INPUT), 26397);
-        assert_eq!(part1(&fs::read_to_string(INPUT_PATH).unwrap()), 321237);
+        assert_eq!(part1(&parse_input(INPUT).unwrap()), 26397);
+        assert_eq!(part1(&parse_input(&load(10).unwrap()).unwrap()), 321237);
     }
 
     #[test]
     fn day10_part2() {
-        assert_eq!(part2(INPUT), 288957);
-        assert_eq!(part2(&fs::read_to_string(INPUT_PATH).unwrap()), 2360030859);
+        assert_eq!(part2(&parse_input(INPUT).unwrap()), Ok(288957));
+        assert_eq!(
+            part2(&parse_input(&load(10).unwrap()).unwrap()),
+            Ok(2360030859)
+        );
+    }
+
+    #[test]
+    fn day10_inputs() {
+        crate::answers::check_inputs(&Day10);
     }
 }
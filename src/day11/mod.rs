@@ -0,0 +1,145 @@
+use std::collections::HashSet;
+
+use crate::error::{Error, Result};
+use crate::grid::{Grid, Pos};
+use crate::solution::{Answer, Solution};
+
+fn parse_input(input: &str) -> Result<Grid<u32>> {
+    Grid::parse_digits(input)
+}
+
+fn step_grid(grid: &mut Grid<u32>) -> usize {
+    let mut hitmap: HashSet<Pos> = HashSet::new();
+
+    for pos in grid.positions() {
+        let octopus = &mut grid[pos];
+        *octopus += 1;
+
+        if *octopus == 10 {
+            hitmap.insert(pos);
+        }
+    }
+
+    while !hitmap.is_empty() {
+        let mut round_hitmap: HashSet<Pos> = HashSet::new();
+
+        for &pos in &hitmap {
+            let neighbours: Vec<Pos> = grid.neighbours8(pos).collect();
+
+            for next in neighbours {
+                let octopus = &mut grid[next];
+                if *octopus == 10 {
+                    continue;
+                } else if *octopus == 9 {
+                    round_hitmap.insert(next);
+                }
+
+                *octopus += 1;
+            }
+        }
+
+        hitmap = round_hitmap;
+    }
+
+    let mut flashes = 0;
+
+    for octopus in grid.cells_mut() {
+        if *octopus == 10 {
+            *octopus = 0;
+            flashes += 1;
+        }
+    }
+
+    flashes
+}
+
+fn part1(grid: &Grid<u32>) -> usize {
+    let mut grid = grid.clone();
+
+    let mut flashes = 0;
+
+    for _ in 0..100 {
+        flashes += step_grid(&mut grid);
+    }
+
+    flashes
+}
+
+fn part2(grid: &Grid<u32>) -> Result<u32> {
+    let mut grid = grid.clone();
+    let octopuses = grid.len();
+
+    // The energy levels are bound to repeat, after which they never all flash at once
+    let mut seen = HashSet::new();
+
+    let mut i = 1;
+
+    while seen.insert(grid.clone()) {
+        let flashes = step_grid(&mut grid);
+        if flashes == octopuses {
+            return Ok(i);
+        }
+
+        i += 1;
+    }
+
+    Err(Error::new("the octopuses never all flash at the same time"))
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Dumbo Octopus";
+
+    type Input = Grid<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(grid: &Self::Input) -> Result<Answer> {
+        Ok(part1(grid).into())
+    }
+
+    fn part2(grid: &Self::Input) -> Result<Option<Answer>> {
+        Ok(Some(part2(grid)?.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::load;
+    const INPUT: &str = "5483143223
+2745854711
+5264556173
+6141336146
+6357385478
+4167524645
+2176841721
+6882881134
+4846848554
+5283751526";
+
+    #[test]
+    fn day11_part1() {
+        assert_eq!(part1(&parse_input(INPUT).unwrap()), 1656);
+        assert_eq!(part1(&parse_input(&load(11).unwrap()).unwrap()), 1603);
+    }
+
+    #[test]
+    fn day11_part2() {
+        assert_eq!(part2(&parse_input(INPUT).unwrap()), Ok(195));
+        assert_eq!(part2(&parse_input(&load(11).unwrap()).unwrap()), Ok(222));
+        assert_eq!(
+            part2(&parse_input("04").unwrap()).unwrap_err().to_string(),
+            "the octopuses never all flash at the same time"
+        );
+    }
+
+    #[test]
+    fn day11_inputs() {
+        crate::answers::check_inputs(&Day11);
+    }
+}
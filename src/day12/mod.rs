@@ -0,0 +1,179 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::error::{Error, Result};
+use crate::parse;
+use crate::solution::{Answer, Solution};
+
+fn is_small_cave(s: &str) -> bool {
+    s.chars().all(char::is_lowercase)
+}
+
+/// Cave system as an adjacency list.
+pub type Graph = HashMap<String, Vec<String>>;
+
+fn visit_node<'a>(
+    graph: &'a Graph,
+    start_node: &'a str,
+    mut hitmap: HashSet<&'a str>,
+    allow_double_visit: bool,
+) -> u32 {
+    if is_small_cave(start_node) {
+        hitmap.insert(start_node);
+    }
+
+    let mut score = 0;
+
+    for edge in graph
+        .get(start_node)
+        .unwrap()
+        .iter()
+        .filter(|&x| x != "start")
+    {
+        if edge == "end" {
+            score += 1;
+        } else if !is_small_cave(edge) || !&hitmap.contains(edge.as_str()) {
+            score += visit_node(graph, edge, hitmap.clone(), allow_double_visit);
+        } else if allow_double_visit {
+            score += visit_node(graph, edge, hitmap.clone(), false);
+        }
+    }
+
+    score
+}
+
+fn parse_input(input: &str) -> Result<Graph> {
+    let mut graph = Graph::new();
+
+    for line in parse::lines(input) {
+        let (start, end) = line.split_once(line.text, "-")?;
+
+        // Paths could go back and forth between two big caves forever
+        if !is_small_cave(start) && !is_small_cave(end) {
+            return Err(line.error_at(
+                0,
+                format!("big caves '{}' and '{}' are connected", start, end),
+            ));
+        }
+
+        let e = graph.entry(start.to_string()).or_default();
+        e.push(end.to_string());
+
+        let e = graph.entry(end.to_string()).or_default();
+        e.push(start.to_string());
+    }
+
+    Ok(graph)
+}
+
+fn solve(graph: &Graph, allow_double_visit: bool) -> Result<u32> {
+    if !graph.contains_key("start") || !graph.contains_key("end") {
+        return Err(Error::new("no path from 'start' to 'end'"));
+    }
+
+    Ok(visit_node(
+        graph,
+        "start",
+        HashSet::new(),
+        allow_double_visit,
+    ))
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const TITLE: &'static str = "Passage Pathing";
+
+    type Input = Graph;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(graph: &Self::Input) -> Result<Answer> {
+        Ok(solve(graph, false)?.into())
+    }
+
+    fn part2(graph: &Self::Input) -> Result<Option<Answer>> {
+        Ok(Some(solve(graph, true)?.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::load;
+
+    const INPUT1: &str = "start-A
+start-b
+A-c
+A-b
+b-d
+A-end
+b-end";
+
+    const INPUT2: &str = "dc-end
+HN-start
+start-kj
+dc-start
+dc-HN
+LN-dc
+HN-end
+kj-sa
+kj-HN
+kj-dc";
+
+    const INPUT3: &str = "fs-end
+he-DX
+fs-he
+start-DX
+pj-DX
+end-zg
+zg-sl
+zg-pj
+pj-he
+RW-he
+fs-DX
+pj-RW
+zg-RW
+start-pj
+he-WI
+zg-he
+pj-fs
+start-RW";
+
+    #[test]
+    fn day12_part1() {
+        assert_eq!(solve(&parse_input(INPUT1).unwrap(), false), Ok(10));
+        assert_eq!(solve(&parse_input(INPUT2).unwrap(), false), Ok(19));
+        assert_eq!(solve(&parse_input(INPUT3).unwrap(), false), Ok(226));
+        assert_eq!(
+            solve(&parse_input(&load(12).unwrap()).unwrap(), false),
+            Ok(3495)
+        );
+    }
+
+    #[test]
+    fn day12_part2() {
+        assert_eq!(solve(&parse_input(INPUT1).unwrap(), true), Ok(36));
+        assert_eq!(solve(&parse_input(INPUT2).unwrap(), true), Ok(103));
+        assert_eq!(solve(&parse_input(INPUT3).unwrap(), true), Ok(3509));
+        assert_eq!(
+            solve(&parse_input(&load(12).unwrap()).unwrap(), true),
+            Ok(94849)
+        );
+    }
+
+    #[test]
+    fn day12_errors() {
+        assert_eq!(
+            parse_input("start-A\nA-B\nB-end").unwrap_err().to_string(),
+            "line 2, column 1: big caves 'A' and 'B' are connected"
+        );
+    }
+
+    #[test]
+    fn day12_inputs() {
+        crate::answers::check_inputs(&Day12);
+    }
+}
@@ -0,0 +1,206 @@
+use std::collections::HashSet;
+
+use crate::error::{Error, Result};
+use crate::geometry::Point2;
+use crate::parse;
+use crate::solution::{Answer, Solution};
+
+#[derive(Debug)]
+pub struct Fold {
+    axis: char,
+    value: i32,
+}
+
+fn render_dots(dots: &HashSet<Point2>) -> Result<String> {
+    let no_dot = || Error::new("no dot on the paper");
+    let rows = dots.iter().map(|dot| dot.y).max().ok_or_else(no_dot)? + 1;
+    let columns = dots.iter().map(|dot| dot.x).max().ok_or_else(no_dot)? + 1;
+
+    let mut grid = vec![vec!['.'; columns as usize]; rows as usize];
+
+    for p in dots {
+        grid[p.y as usize][p.x as usize] = '#';
+    }
+
+    Ok(grid
+        .iter()
+        .map(|row| row.iter().collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n"))
+}
+
+pub struct Manual {
+    dots: HashSet<Point2>,
+    folds: Vec<Fold>,
+}
+
+fn parse_input(input: &str) -> Result<Manual> {
+    let mut lines = parse::lines(input);
+
+    let mut dots: HashSet<Point2> = HashSet::new();
+    let mut folds: Vec<Fold> = vec![];
+
+    // Parse dots, up to the blank line
+    for line in lines.by_ref().take_while(|l| !l.text.is_empty()) {
+        let dot = Point2::parse(&line, line.text)?;
+        if dot.x < 0 || dot.y < 0 {
+            return Err(line.error_at(0, "dot is off the paper"));
+        }
+
+        dots.insert(dot);
+    }
+
+    // Parse folds
+    for line in lines {
+        let fold = line.strip_prefix(line.text, "fold along ")?;
+        let (axis, value) = line.split_once(fold, "=")?;
+
+        if axis != "x" && axis != "y" {
+            return Err(line.error(axis, format!("expected 'x' or 'y', found '{}'", axis)));
+        }
+
+        folds.push(Fold {
+            axis: if axis == "x" { 'x' } else { 'y' },
+            value: line.parse(value)?,
+        });
+    }
+
+    Ok(Manual { dots, folds })
+}
+
+fn do_fold(mut dots: HashSet<Point2>, fold: &Fold) -> Result<HashSet<Point2>> {
+    type FilterCb = Box<dyn Fn(&Point2, i32) -> bool>;
+    type MapDotCb = Box<dyn Fn(&Point2, i32) -> Point2>;
+
+    let (filter_cb, map_dot_cb) = if fold.axis == 'y' {
+        let filter_cb: FilterCb = Box::new(|dot: &Point2, fold_value: i32| dot.y >= fold_value);
+
+        let map_dot_cb: MapDotCb = Box::new(|dot: &Point2, fold_value: i32| Point2 {
+            x: dot.x,
+            y: fold_value - (dot.y - fold_value),
+        });
+
+        (filter_cb, map_dot_cb)
+    } else {
+        let filter_cb: FilterCb = Box::new(|dot: &Point2, fold_value: i32| dot.x >= fold_value);
+
+        let map_dot_cb: MapDotCb = Box::new(|dot: &Point2, fold_value: i32| Point2 {
+            x: fold_value - (dot.x - fold_value),
+            y: dot.y,
+        });
+
+        (filter_cb, map_dot_cb)
+    };
+
+    let to_move: HashSet<_> = dots
+        .iter()
+        .filter(|dot| filter_cb(dot, fold.value))
+        .copied()
+        .collect();
+
+    dots = dots.difference(&to_move).copied().collect();
+
+    for dot in &to_move {
+        let dest = map_dot_cb(dot, fold.value);
+        if dest.x < 0 || dest.y < 0 {
+            return Err(Error::new(format!(
+                "fold along {}={} sends dot {} off the paper",
+                fold.axis, fold.value, dot
+            )));
+        }
+
+        dots.insert(dest);
+    }
+
+    Ok(dots)
+}
+
+fn part1(manual: &Manual) -> Result<usize> {
+    let fold = manual
+        .folds
+        .first()
+        .ok_or_else(|| Error::new("no fold instruction"))?;
+
+    let dots = do_fold(manual.dots.clone(), fold)?;
+    Ok(dots.len())
+}
+
+fn part2(manual: &Manual) -> Result<String> {
+    let mut dots = manual.dots.clone();
+
+    for fold in &manual.folds {
+        dots = do_fold(dots, fold)?;
+    }
+
+    render_dots(&dots)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const TITLE: &'static str = "Transparent Origami";
+
+    type Input = Manual;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(manual: &Self::Input) -> Result<Answer> {
+        Ok(part1(manual)?.into())
+    }
+
+    fn part2(manual: &Self::Input) -> Result<Option<Answer>> {
+        Ok(Some(part2(manual)?.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::load;
+    const INPUT: &str = "6,10
+0,14
+9,10
+0,3
+10,4
+4,11
+6,0
+6,12
+4,1
+0,13
+10,12
+3,4
+3,0
+8,4
+1,10
+2,14
+8,10
+9,0
+
+fold along y=7
+fold along x=5";
+
+    #[test]
+    fn day13_part1() {
+        assert_eq!(part1(&parse_input(INPUT).unwrap()), Ok(17));
+        assert_eq!(part1(&parse_input(&load(13).unwrap()).unwrap()), Ok(712));
+    }
+
+    #[test]
+    fn day13_errors() {
+        let err = |input| part2(&parse_input(input).unwrap()).unwrap_err().to_string();
+
+        assert_eq!(
+            err("1,1\n\nfold along y=0"),
+            "fold along y=0 sends dot 1,1 off the paper"
+        );
+        assert_eq!(err("\nfold along x=3"), "no dot on the paper");
+    }
+
+    #[test]
+    fn day13_inputs() {
+        crate::answers::check_inputs(&Day13);
+    }
+}
@@ -0,0 +1,169 @@
+use std::collections::HashMap;
+
+use crate::error::{Error, Result};
+use crate::parse::{self, Line};
+use crate::solution::{Answer, Solution};
+
+pub struct Polymer {
+    template: String,
+    rules: HashMap<String, String>,
+}
+
+fn parse_elements<'a>(line: &Line<'a>, part: &'a str, count: Option<usize>) -> Result<String> {
+    if let Some(offset) = part.find(|c: char| !c.is_ascii_uppercase()) {
+        return Err(line.error(&part[offset..], "expected an element"));
+    }
+
+    match count {
+        Some(count) if part.len() != count => Err(line.error(
+            part,
+            format!("expected {} elements, found {}", count, part.len()),
+        )),
+        _ => Ok(part.to_string()),
+    }
+}
+
+fn parse_input(input: &str) -> Result<Polymer> {
+    let mut lines = parse::lines(input);
+
+    let template = match lines.next() {
+        Some(line) if !line.text.is_empty() => parse_elements(&line, line.text, None)?,
+        _ => return Err(Error::new("missing polymer template")),
+    };
+
+    let rules = lines
+        .skip_while(|l| l.text.is_empty())
+        .map(|line| {
+            let (pair, target) = line.split_once(line.text, " -> ")?;
+
+            Ok((
+                parse_elements(&line, pair, Some(2))?,
+                parse_elements(&line, target, Some(1))?,
+            ))
+        })
+        .collect::<Result<_>>()?;
+
+    Ok(Polymer { template, rules })
+}
+
+fn solve(polymer: &Polymer, steps: u32) -> Result<u64> {
+    let Polymer { template, rules } = polymer;
+
+    // List initial pairs
+    let mut pairs: HashMap<String, u64> = HashMap::new();
+
+    for i in 0..template.len() - 1 {
+        let pair = &template[i..i + 2];
+
+        let entry = pairs.entry(pair.to_string()).or_insert(0);
+        *entry += 1;
+    }
+
+    // Do steps
+    for _ in 0..steps {
+        let mut new_pairs: HashMap<String, u64> = HashMap::new();
+
+        let mut inject_pair = |pair: String, count: u64| {
+            let entry = new_pairs.entry(pair).or_insert(0);
+            *entry += count;
+        };
+
+        for (pair, count) in pairs.iter() {
+            let first_char = pair.chars().next().unwrap();
+            let second_char = pair.chars().nth(1).unwrap();
+            let target = rules
+                .get(&pair[..])
+                .ok_or_else(|| Error::new(format!("no rule for pair {}", pair)))?;
+
+            inject_pair(format!("{}{}", first_char, target), *count);
+            inject_pair(format!("{}{}", target, second_char), *count);
+        }
+
+        pairs = new_pairs;
+    }
+
+    // Compute frequency
+    let mut frequency: HashMap<char, u64> = HashMap::new();
+    for (pair, count) in &pairs {
+        let c = pair.chars().next().unwrap();
+
+        let entry = frequency.entry(c).or_insert(0);
+        *entry += count;
+    }
+
+    // Let char isn't include in the previous step
+    let last_char = template.chars().last().unwrap();
+    let entry = frequency.entry(last_char).or_insert(0);
+    *entry += 1;
+
+    Ok(frequency.values().max().unwrap() - frequency.values().min().unwrap())
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const TITLE: &'static str = "Extended Polymerization";
+
+    type Input = Polymer;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(polymer: &Self::Input) -> Result<Answer> {
+        Ok(solve(polymer, 10)?.into())
+    }
+
+    fn part2(polymer: &Self::Input) -> Result<Option<Answer>> {
+        Ok(Some(solve(polymer, 40)?.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::load;
+    const INPUT: &str = "NNCB
+
+CH -> B
+HH -> N
+CB -> H
+NH -> C
+HB -> C
+HC -> B
+HN -> C
+NN -> C
+BH -> H
+NC -> B
+NB -> B
+BN -> B
+BB -> N
+BC -> B
+CC -> N
+CN -> C";
+
+    #[test]
+    fn day14_part1() {
+        assert_eq!(solve(&parse_input(INPUT).unwrap(), 10), Ok(1588));
+        assert_eq!(
+            solve(&parse_input(&load(14).unwrap()).unwrap(), 10),
+            Ok(2010)
+        );
+    }
+
+    #[test]
+    fn day14_part2() {
+        assert_eq!(solve(&parse_input(INPUT).unwrap(), 10), Ok(1588));
+        assert_eq!(solve(&parse_input(INPUT).unwrap(), 40), Ok(2188189693529));
+        assert_eq!(
+            solve(&parse_input(&load(14).unwrap()).unwrap(), 40),
+            Ok(2437698971143)
+        );
+    }
+
+    #[test]
+    fn day14_inputs() {
+        crate::answers::check_inputs(&Day14);
+    }
+}
@@ -0,0 +1,143 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use crate::error::Result;
+use crate::grid::{Grid, Pos};
+use crate::solution::{Answer, Solution};
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+struct Node {
+    cost: u32,
+    position: Pos,
+}
+
+impl Ord for Node {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.cost.cmp(&self.cost)
+    }
+}
+
+impl PartialOrd for Node {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn parse_input(input: &str) -> Result<Grid<u32>> {
+    Grid::parse(input, "a risk level from 1 to 9", |c| {
+        c.to_digit(10).filter(|&d| d > 0)
+    })
+}
+
+fn solve(tile: &Grid<u32>, tile_count: usize) -> u32 {
+    // Expand the tile, the risk grows with the tile index and wraps from 9 back to 1
+    let graph = Grid::from_fn(
+        tile.width() * tile_count,
+        tile.height() * tile_count,
+        |(x, y)| {
+            let x_tile_idx = (x / tile.width()) as u32;
+            let y_tile_idx = (y / tile.height()) as u32;
+            let risk = tile[(x % tile.width(), y % tile.height())];
+
+            (risk + x_tile_idx + y_tile_idx - 1) % 9 + 1
+        },
+    );
+    let end = (graph.width() - 1, graph.height() - 1);
+
+    let mut dist = graph.map(|_| u32::MAX);
+    dist[(0, 0)] = 0;
+
+    let mut heap: BinaryHeap<Node> = BinaryHeap::new();
+    heap.push(Node {
+        cost: 0,
+        position: (0, 0),
+    });
+
+    while let Some(node) = heap.pop() {
+        if node.position == end {
+            return node.cost;
+        }
+
+        if node.cost > dist[node.position] {
+            continue;
+        }
+
+        for target in graph.neighbours4(node.position) {
+            // Update costs if required
+            let cost = node.cost + graph[target];
+            if cost < dist[target] {
+                heap.push(Node {
+                    position: target,
+                    cost,
+                });
+
+                dist[target] = cost;
+            }
+        }
+    }
+
+    0
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    const TITLE: &'static str = "Chiton";
+
+    type Input = Grid<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(graph: &Self::Input) -> Result<Answer> {
+        Ok(solve(graph, 1).into())
+    }
+
+    fn part2(graph: &Self::Input) -> Result<Option<Answer>> {
+        Ok(Some(solve(graph, 5).into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::load;
+
+    const INPUT: &str = "1163751742
+1381373672
+2136511328
+3694931569
+7463417111
+1319128137
+1359912421
+3125421639
+1293138521
+2311944581";
+
+    #[test]
+    fn day15_part1() {
+        assert_eq!(solve(&parse_input(INPUT).unwrap(), 1), 40);
+        assert_eq!(solve(&parse_input(&load(15).unwrap()).unwrap(), 1), 429);
+    }
+
+    #[test]
+    fn day15_part2() {
+        assert_eq!(solve(&parse_input(INPUT).unwrap(), 5), 315);
+        assert_eq!(solve(&parse_input(&load(15).unwrap()).unwrap(), 5), 2844);
+    }
+
+    #[test]
+    fn day15_errors() {
+        assert_eq!(
+            parse_input("10\n01").unwrap_err().to_string(),
+            "line 1, column 2: expected a risk level from 1 to 9, found '0'"
+        );
+    }
+
+    #[test]
+    fn day15_inputs() {
+        crate::answers::check_inputs(&Day15);
+    }
+}
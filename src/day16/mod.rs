@@ -0,0 +1,264 @@
+use std::cmp::min;
+
+use crate::error::{Error, Result};
+use crate::parse::{self, Line};
+use crate::solution::{Answer, Solution};
+
+#[derive(Debug)]
+struct BitReader {
+    data: Vec<u8>,
+    read_idx: usize,
+
+    current_byte: u8,
+    current_len: usize,
+}
+
+impl BitReader {
+    fn new(line: &Line) -> Result<Self> {
+        let mut digits = line.text.char_indices().map(|(offset, c)| {
+            c.to_digit(16).map(|d| d as u8).ok_or_else(|| {
+                line.error_at(offset, format!("expected a hex digit, found '{}'", c))
+            })
+        });
+
+        let current_byte = digits
+            .next()
+            .ok_or_else(|| line.error_at(0, "empty transmission"))??;
+        let data = digits.collect::<Result<Vec<u8>>>()?;
+
+        Ok(Self {
+            data,
+            read_idx: 0,
+            current_byte,
+            current_len: 4,
+        })
+    }
+
+    fn read(&mut self, mut count: usize) -> Result<u64> {
+        let mut v: u64 = 0;
+
+        while count > 0 {
+            if self.current_len == 0 {
+                return Err(Error::new("unexpected end of transmission"));
+            }
+
+            let chunk_size = min(count, self.current_len);
+
+            v <<= chunk_size;
+            v |= (self.current_byte >> (4 - chunk_size)) as u64;
+
+            self.current_byte = (self.current_byte << chunk_size) & 0b1111;
+            self.current_len -= chunk_size;
+            count -= chunk_size;
+
+            if self.current_len == 0 && self.read_idx < self.data.len() {
+                self.current_byte = self.data[self.read_idx];
+                self.current_len = 4;
+                self.read_idx += 1;
+            }
+        }
+
+        Ok(v)
+    }
+}
+
+#[derive(Debug)]
+pub struct ReadResult {
+    version_sum: u64,
+    packet_len: u64,
+    value: u64,
+}
+
+fn read_packet(reader: &mut BitReader) -> Result<ReadResult> {
+    let mut version_sum = reader.read(3)?;
+    let type_ = reader.read(3)?;
+
+    let mut packet_len = 6;
+
+    let value = if type_ == 4 {
+        let mut subpacket_value = 0;
+        let mut subpacket_len = 0;
+
+        loop {
+            let t = reader.read(5)?;
+            subpacket_len += 5;
+
+            subpacket_value = (subpacket_value << 4) | (t & 0b1111);
+
+            if t & 0b10000 == 0 {
+                break;
+            }
+        }
+
+        packet_len += subpacket_len;
+
+        subpacket_value
+    } else {
+        let len_type = reader.read(1)?;
+        packet_len += 1;
+
+        let mut subpackets = vec![];
+
+        if len_type == 0 {
+            let mut remaining = reader.read(15)?;
+            packet_len += 15;
+
+            while remaining > 0 {
+                let subpacket = read_packet(reader)?;
+                subpackets.push(subpacket.value);
+
+                remaining = remaining
+                    .checked_sub(subpacket.packet_len)
+                    .ok_or_else(|| Error::new("sub-packet exceeds the length of its parent"))?;
+                packet_len += subpacket.packet_len;
+                version_sum += subpacket.version_sum;
+            }
+        } else {
+            let count = reader.read(11)?;
+            packet_len += 11;
+
+            for _ in 0..count {
+                let subpacket = read_packet(reader)?;
+                subpackets.push(subpacket.value);
+
+                packet_len += subpacket.packet_len;
+                version_sum += subpacket.version_sum;
+            }
+        }
+
+        if subpackets.is_empty() {
+            return Err(Error::new(format!(
+                "operator of type {} without operand",
+                type_
+            )));
+        }
+        if type_ >= 5 && subpackets.len() != 2 {
+            return Err(Error::new(format!(
+                "comparison of type {} expects 2 operands, found {}",
+                type_,
+                subpackets.len()
+            )));
+        }
+
+        match type_ {
+            0 => subpackets.iter().sum(),
+            1 => subpackets.iter().product(),
+            2 => subpackets.iter().copied().min().unwrap(),
+            3 => subpackets.iter().copied().max().unwrap(),
+            5 => (subpackets[0] > subpackets[1]) as u64,
+            6 => (subpackets[0] < subpackets[1]) as u64,
+            7 => (subpackets[0] == subpackets[1]) as u64,
+            _ => return Err(Error::new(format!("unexpected packet type {}", type_))),
+        }
+    };
+
+    Ok(ReadResult {
+        version_sum,
+        packet_len,
+        value,
+    })
+}
+
+fn parse_input(input: &str) -> Result<ReadResult> {
+    let line = parse::lines(input)
+        .next()
+        .ok_or_else(|| Error::new("empty transmission"))?;
+    let mut reader = BitReader::new(&line)?;
+
+    read_packet(&mut reader)
+}
+
+fn part1(result: &ReadResult) -> u64 {
+    result.version_sum
+}
+
+fn part2(result: &ReadResult) -> u64 {
+    result.value
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    const TITLE: &'static str = "Packet Decoder";
+
+    type Input = ReadResult;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(result: &Self::Input) -> Result<Answer> {
+        Ok(part1(result).into())
+    }
+
+    fn part2(result: &Self::Input) -> Result<Option<Answer>> {
+        Ok(Some(part2(result).into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::load;
+
+    #[test]
+    fn day16_part1() {
+        assert_eq!(part1(&parse_input("8A004A801A8002F478").unwrap()), 16);
+        assert_eq!(
+            part1(&parse_input("620080001611562C8802118E34").unwrap()),
+            12
+        );
+        assert_eq!(
+            part1(&parse_input("C0015000016115A2E0802F182340").unwrap()),
+            23
+        );
+        assert_eq!(
+            part1(&parse_input("A0016C880162017C3686B18A3D4780").unwrap()),
+            31
+        );
+        assert_eq!(part1(&parse_input(&load(16).unwrap()).unwrap()), 957);
+    }
+
+    #[test]
+    fn day16_part2() {
+        assert_eq!(part2(&parse_input("C200B40A82").unwrap()), 3);
+        assert_eq!(part2(&parse_input("04005AC33890").unwrap()), 54);
+        assert_eq!(part2(&parse_input("880086C3E88112").unwrap()), 7);
+        assert_eq!(part2(&parse_input("CE00C43D881120").unwrap()), 9);
+        assert_eq!(part2(&parse_input("D8005AC2A8F0").unwrap()), 1);
+        assert_eq!(part2(&parse_input("F600BC2D8F").unwrap()), 0);
+        assert_eq!(part2(&parse_input("9C005AC2F8F0").unwrap()), 0);
+        assert_eq!(
+            part2(&parse_input("9C0141080250320F1802104A08").unwrap()),
+            1
+        );
+        assert_eq!(
+            part2(&parse_input(&load(16).unwrap()).unwrap()),
+            744953223228
+        );
+    }
+
+    #[test]
+    fn day16_errors() {
+        let err = |input| parse_input(input).unwrap_err().to_string();
+
+        assert_eq!(
+            err("8A00G"),
+            "line 1, column 5: expected a hex digit, found 'G'"
+        );
+        assert_eq!(err(""), "empty transmission");
+        // Literal value whose last group is cut
+        assert_eq!(err("D2FE2"), "unexpected end of transmission");
+        // Comparison of type 5 with a single literal operand
+        assert_eq!(
+            err("16004408"),
+            "comparison of type 5 expects 2 operands, found 1"
+        );
+    }
+
+    #[test]
+    fn day16_inputs() {
+        crate::answers::check_inputs(&Day16);
+    }
+}
@@ -0,0 +1,223 @@
+use crate::error::{Error, Result};
+use crate::parse;
+use crate::solution::{Answer, Solution};
+
+#[derive(Debug)]
+pub struct Range {
+    min: i32,
+    max: i32,
+}
+
+impl Range {
+    fn contains(&self, x: i32) -> bool {
+        self.min <= x && x <= self.max
+    }
+}
+
+#[derive(Debug)]
+struct Trajectory {
+    max_y: i32,
+}
+
+/// Bound on the coordinates of the target, so that every position and velocity of the probe
+/// computed by the solver fits in an `i32`.
+const MAX_COORDINATE: i32 = 10_000;
+
+fn parse_input(input: &str) -> Result<(Range, Range)> {
+    // target area: x=20..30, y=-10..-5
+    let line = parse::lines(input)
+        .next()
+        .ok_or_else(|| Error::new("empty input"))?;
+
+    let area = line.strip_prefix(line.text, "target area: ")?;
+    let (x, y) = line.split_once(area, ", ")?;
+
+    let split_range = |input: &str, prefix: &str| -> Result<Range> {
+        let (min, max) = line.split_once(line.strip_prefix(input, prefix)?, "..")?;
+        let range = Range {
+            min: line.parse(min)?,
+            max: line.parse(max)?,
+        };
+
+        if range.min > range.max {
+            return Err(line.error(input, "range is reversed"));
+        }
+        if range.min < -MAX_COORDINATE || range.max > MAX_COORDINATE {
+            return Err(line.error(
+                input,
+                format!(
+                    "coordinates must be between {} and {}",
+                    -MAX_COORDINATE, MAX_COORDINATE
+                ),
+            ));
+        }
+
+        Ok(range)
+    };
+
+    let target = (split_range(x, "x=")?, split_range(y, "y=")?);
+
+    // The solver relies on the target being ahead and below the probe
+    if target.0.min <= 0 {
+        return Err(line.error(x, "target must be on the right of the probe"));
+    }
+    if target.1.max >= 0 {
+        return Err(line.error(y, "target must be below the probe"));
+    }
+
+    Ok(target)
+}
+
+fn compute_x(x0: i32, n: i32) -> i32 {
+    if n > x0 {
+        compute_x(x0, x0)
+    } else {
+        n * x0 - ((n - 1) * n) / 2
+    }
+}
+
+fn compute_y(x0: i32, n: i32) -> i32 {
+    n * x0 - ((n - 1) * n) / 2
+}
+
+fn solve(target: &(Range, Range)) -> Vec<Trajectory> {
+    let (range_x, range_y) = target;
+
+    // Get all valid x0
+    let valid_x: Vec<i32> = {
+        let mut valid_x: Vec<i32> = (range_x.min..range_x.max + 1).collect();
+
+        for x0 in 1..range_x.min {
+            for n in 1..x0 + 1 {
+                let x = compute_x(x0, n);
+
+                if x < range_x.min {
+                    continue;
+                } else if x > range_x.max {
+                    break;
+                } else {
+                    valid_x.push(x0);
+                    break;
+                }
+            }
+        }
+
+        valid_x
+    };
+
+    // Get all valid y0
+    let valid_y: Vec<i32> = (range_y.min..range_y.min.abs()).collect();
+
+    let mut solutions = vec![];
+
+    for x0 in valid_x {
+        for y0 in &valid_y {
+            let mut trajectory_max_y = i32::MIN;
+
+            for n in 1.. {
+                // x will always be valid
+                let x = compute_x(x0, n);
+                if x < range_x.min {
+                    continue;
+                }
+
+                // y can be out of range
+                let y = compute_y(*y0, n);
+                if y < range_y.min {
+                    break;
+                }
+
+                trajectory_max_y = std::cmp::max(trajectory_max_y, y);
+
+                if range_x.contains(x) && range_y.contains(y) {
+                    solutions.push(Trajectory {
+                        max_y: trajectory_max_y,
+                    });
+
+                    break;
+                }
+            }
+        }
+    }
+
+    solutions
+}
+
+fn part1(target: &(Range, Range)) -> Result<i32> {
+    let results = solve(target);
+
+    results
+        .iter()
+        .map(|x| x.max_y)
+        .max()
+        .ok_or_else(|| Error::new("no trajectory reaches the target"))
+}
+
+fn part2(target: &(Range, Range)) -> usize {
+    let results = solve(target);
+    results.len()
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    const TITLE: &'static str = "Trick Shot";
+
+    type Input = (Range, Range);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(target: &Self::Input) -> Result<Answer> {
+        Ok(part1(target)?.into())
+    }
+
+    fn part2(target: &Self::Input) -> Result<Option<Answer>> {
+        Ok(Some(part2(target).into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::load;
+
+    const INPUT: &str = "target area: x=20..30, y=-10..-5";
+
+    #[test]
+    fn day17_part1() {
+        assert_eq!(part1(&parse_input(INPUT).unwrap()), Ok(45));
+        assert_eq!(part1(&parse_input(&load(17).unwrap()).unwrap()), Ok(3916));
+    }
+
+    #[test]
+    fn day17_part2() {
+        assert_eq!(part2(&parse_input(INPUT).unwrap()), 112);
+        assert_eq!(part2(&parse_input(&load(17).unwrap()).unwrap()), 2986);
+    }
+
+    #[test]
+    fn day17_errors() {
+        let err = |input| parse_input(input).unwrap_err().to_string();
+
+        assert_eq!(
+            err("target area: x=1..2147483647, y=-2147483648..-1"),
+            "line 1, column 14: coordinates must be between -10000 and 10000"
+        );
+        assert_eq!(
+            err("target area: x=20..30, y=-10001..-5"),
+            "line 1, column 24: coordinates must be between -10000 and 10000"
+        );
+        assert_eq!(
+            err("target area: x=30..20, y=-10..-5"),
+            "line 1, column 14: range is reversed"
+        );
+    }
+
+    #[test]
+    fn day17_inputs() {
+        crate::answers::check_inputs(&Day17);
+    }
+}
@@ -1,79 +1,100 @@
 use std::cell::RefCell;
 use std::cmp::max;
-use std::fs;
 use std::rc::Rc;
 
-const INPUT_PATH: &str = "src/day18/input.txt";
+use crate::error::{Error, Result};
+use crate::parse::{self, Line};
+use crate::solution::{Answer, Solution};
 
 #[derive(Debug)]
-struct Edges {
+pub struct Edges {
     left: Rc<RefCell<Node>>,
     right: Rc<RefCell<Node>>,
 }
 
 #[derive(Debug)]
-enum Node {
+pub enum Node {
     Number(u32),
     Edges(Edges),
 }
 
 impl Node {
-    fn parse_input(input: &str) -> (Rc<RefCell<Node>>, usize) {
-        assert_eq!(input.chars().next().unwrap(), '[');
-        assert_eq!(input.chars().last().unwrap(), ']');
+    // Parse the node starting at byte `pos` of the line and move past it
+    fn parse_node(line: &Line, pos: &mut usize) -> Result<Rc<RefCell<Node>>> {
+        let text = line.text;
+
+        let node = match text[*pos..].chars().next() {
+            Some('[') => {
+                *pos += 1;
+                let left = Self::parse_node(line, pos)?;
+                Self::expect(line, pos, ',')?;
+                let right = Self::parse_node(line, pos)?;
+                Self::expect(line, pos, ']')?;
+
+                Node::Edges(Edges { left, right })
+            }
+            Some(c) if c.is_ascii_digit() => {
+                let start = *pos;
+                *pos += text[start..]
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(text.len() - start);
 
-        // Parse left
-        let left = &input[1..];
-        let first_char = left.chars().next().unwrap();
+                Node::Number(line.parse(&text[start..*pos])?)
+            }
+            Some(c) => {
+                return Err(line.error_at(*pos, format!("expected '[' or a digit, found '{}'", c)))
+            }
+            None => return Err(line.error_at(*pos, "unexpected end of line")),
+        };
 
-        let (separator_idx, left_node) = if first_char == '[' {
-            let (subnode, subnode_len) = Self::parse_input(left);
-            (1 + subnode_len + 1, subnode)
-        } else {
-            assert!(first_char.is_digit(10));
+        Ok(Rc::new(RefCell::new(node)))
+    }
 
-            let separator_idx = left.find(',').unwrap();
-            let value = left[0..separator_idx].parse().unwrap();
+    fn expect(line: &Line, pos: &mut usize, expected: char) -> Result<()> {
+        match line.text[*pos..].chars().next() {
+            Some(c) if c == expected => {
+                *pos += 1;
+                Ok(())
+            }
+            Some(c) => Err(line.error_at(*pos, format!("expected '{}', found '{}'", expected, c))),
+            None => Err(line.error_at(*pos, format!("expected '{}'", expected))),
+        }
+    }
 
-            (
-                1 + separator_idx,
-                Rc::new(RefCell::new(Node::Number(value))),
-            )
-        };
+    fn parse(line: &Line) -> Result<Rc<RefCell<Self>>> {
+        if !line.text.starts_with('[') {
+            return Err(line.error_at(0, "expected a pair"));
+        }
 
-        // Parse right
-        let right_idx = separator_idx + 1;
-        let right = &input[right_idx..];
+        let mut pos = 0;
+        let node = Self::parse_node(line, &mut pos)?;
 
-        let first_char = right.chars().next().unwrap();
+        if pos != line.text.len() {
+            return Err(line.error_at(pos, "unexpected trailing characters"));
+        }
 
-        let (end_idx, right_node) = if first_char == '[' {
-            let (subnode, subnode_len) = Self::parse_input(right);
-            (right_idx + subnode_len + 1, subnode)
-        } else {
-            assert!(first_char.is_digit(10));
+        Ok(node)
+    }
 
-            let end_idx = right.find(']').unwrap();
-            let value = right[0..end_idx].parse().unwrap();
+    #[cfg(test)]
+    fn new(input: &str) -> Result<Rc<RefCell<Self>>> {
+        Self::parse(&Line {
+            number: 1,
+            text: input,
+        })
+    }
 
-            (
-                right_idx + end_idx,
-                Rc::new(RefCell::new(Node::Number(value))),
-            )
+    // Nodes are reduced in place, so each addition needs its own copy of the operands
+    fn deep_copy(&self) -> Rc<RefCell<Self>> {
+        let node = match self {
+            Node::Number(n) => Node::Number(*n),
+            Node::Edges(edges) => Node::Edges(Edges {
+                left: edges.left.borrow().deep_copy(),
+                right: edges.right.borrow().deep_copy(),
+            }),
         };
 
-        (
-            Rc::new(RefCell::new(Node::Edges(Edges {
-                left: left_node,
-                right: right_node,
-            }))),
-            end_idx,
-        )
-    }
-
-    fn new(input: &str) -> Rc<RefCell<Self>> {
-        let (node, _) = Self::parse_input(input);
-        node
+        Rc::new(RefCell::new(node))
     }
 
     fn to_str(&self) -> String {
@@ -250,15 +271,25 @@ fn reduce(node: Rc<RefCell<Node>>) {
     while explode(node.clone()) || split(node.clone()) {}
 }
 
-fn run(input: &str) -> (u32, String) {
-    let lines: Vec<_> = input.lines().collect();
+fn parse_input(input: &str) -> Result<Vec<Rc<RefCell<Node>>>> {
+    let numbers = parse::lines(input)
+        .map(|l| Node::parse(&l))
+        .collect::<Result<Vec<_>>>()?;
+
+    if numbers.is_empty() {
+        return Err(Error::new("no snailfish number"));
+    }
+
+    Ok(numbers)
+}
 
-    let mut node = Node::new(lines[0]);
+fn run(numbers: &[Rc<RefCell<Node>>]) -> (u32, String) {
+    let mut node = numbers[0].borrow().deep_copy();
 
-    for l in lines.iter().skip(1) {
+    for number in numbers.iter().skip(1) {
         node = Rc::new(RefCell::new(Node::Edges(Edges {
             left: node,
-            right: Node::new(l),
+            right: number.borrow().deep_copy(),
         })));
 
         reduce(node.clone());
@@ -268,20 +299,18 @@ fn run(input: &str) -> (u32, String) {
     (node.magnitude(), node.to_str())
 }
 
-fn part1(input: &str) -> u32 {
-    let (magnitude, _) = run(input);
+fn part1(numbers: &[Rc<RefCell<Node>>]) -> u32 {
+    let (magnitude, _) = run(numbers);
     magnitude
 }
 
-fn part2(input: &str) -> u32 {
-    let lines: Vec<_> = input.lines().collect();
-
+fn part2(numbers: &[Rc<RefCell<Node>>]) -> u32 {
     let mut magnitude = u32::MIN;
 
-    let get_magnitude = |a: &str, b: &str| {
+    let get_magnitude = |a: &Rc<RefCell<Node>>, b: &Rc<RefCell<Node>>| {
         let node = Rc::new(RefCell::new(Node::Edges(Edges {
-            left: Node::new(a),
-            right: Node::new(b),
+            left: a.borrow().deep_copy(),
+            right: b.borrow().deep_copy(),
         })));
 
         reduce(node.clone());
@@ -290,51 +319,67 @@ fn part2(input: &str) -> u32 {
         node.magnitude()
     };
 
-    for i in 0..lines.len() - 1 {
-        for j in i + 1..lines.len() {
-            magnitude = max(magnitude, get_magnitude(lines[i], lines[j]));
-            magnitude = max(magnitude, get_magnitude(lines[j], lines[i]));
+    for i in 0..numbers.len() - 1 {
+        for j in i + 1..numbers.len() {
+            magnitude = max(magnitude, get_magnitude(&numbers[i], &numbers[j]));
+            magnitude = max(magnitude, get_magnitude(&numbers[j], &numbers[i]));
         }
     }
 
     magnitude
 }
 
-fn main() {
-    let input = fs::read_to_string(INPUT_PATH).unwrap();
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    const TITLE: &'static str = "Snailfish";
+
+    type Input = Vec<Rc<RefCell<Node>>>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(numbers: &Self::Input) -> Result<Answer> {
+        Ok(part1(numbers).into())
+    }
+
+    fn part2(numbers: &Self::Input) -> Result<Option<Answer>> {
+        Ok(Some(part2(numbers).into()))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::input::load;
 
     fn test_parse_print(input: &str) {
-        let node = Node::new(input);
+        let node = Node::new(input).unwrap();
         let output = node.borrow().to_str();
         assert_eq!(input, output);
     }
 
     fn test_magnitude(input: &str, value: u32) {
-        let node = Node::new(input);
+        let node = Node::new(input).unwrap();
         assert_eq!(node.borrow().magnitude(), value);
     }
 
     fn test_explode(input: &str, output: &str) {
-        let node = Node::new(input);
+        let node = Node::new(input).unwrap();
         assert!(explode(node.clone()));
         assert_eq!(node.borrow().to_str(), output);
     }
 
     fn test_split(input: &str, output: &str) {
-        let node = Node::new(input);
+        let node = Node::new(input).unwrap();
         assert!(split(node.clone()));
         assert_eq!(node.borrow().to_str(), output);
     }
 
     fn test_reduce(input: &str, output: &str) {
-        let node = Node::new(input);
+        let node = Node::new(input).unwrap();
         reduce(node.clone());
         assert_eq!(node.borrow().to_str(), output);
     }
@@ -393,7 +438,7 @@ mod tests {
             "[[[[3,0],[5,3]],[4,4]],[5,5]]",
         );
 
-        const INPUT: &'static str = "[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
+        const INPUT: &str = "[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
 [[[5,[2,8]],4],[5,[[9,9],0]]]
 [6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
 [[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]
@@ -404,19 +449,19 @@ mod tests {
 [[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
 [[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]";
 
-        let (v, s) = run(INPUT);
+        let (v, s) = run(&parse_input(INPUT).unwrap());
         assert_eq!(
             s,
             "[[[[6,6],[7,6]],[[7,7],[7,0]]],[[[7,7],[7,7]],[[7,8],[9,9]]]]"
         );
         assert_eq!(v, 4140);
 
-        assert_eq!(part1(&fs::read_to_string(INPUT_PATH).unwrap()), 4365);
+        assert_eq!(part1(&parse_input(&load(18).unwrap()).unwrap()), 4365);
     }
 
     #[test]
     fn day18_part2() {
-        const INPUT: &'static str = "[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
+        const INPUT: &str = "[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
 [[[5,[2,8]],4],[5,[[9,9],0]]]
 [6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
 [[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]
@@ -427,7 +472,30 @@ mod tests {
 [[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
 [[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]";
 
-        assert_eq!(part2(INPUT), 3993);
-        assert_eq!(part2(&fs::read_to_string(INPUT_PATH).unwrap()), 4490);
+        assert_eq!(part2(&parse_input(INPUT).unwrap()), 3993);
+        assert_eq!(part2(&parse_input(&load(18).unwrap()).unwrap()), 4490);
+    }
+
+    #[test]
+    fn day18_errors() {
+        let err = |input| parse_input(input).unwrap_err().to_string();
+
+        assert_eq!(err("[1,2]\n3"), "line 2, column 1: expected a pair");
+        assert_eq!(
+            err("[[1,2],x]"),
+            "line 1, column 8: expected '[' or a digit, found 'x'"
+        );
+        assert_eq!(err("[[1,2]]"), "line 1, column 7: expected ',', found ']'");
+        assert_eq!(err("[1,2"), "line 1, column 5: expected ']'");
+        assert_eq!(
+            err("[1,2]]"),
+            "line 1, column 6: unexpected trailing characters"
+        );
+        assert_eq!(err(""), "no snailfish number");
+    }
+
+    #[test]
+    fn day18_inputs() {
+        crate::answers::check_inputs(&Day18);
     }
 }
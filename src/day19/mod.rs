@@ -1,41 +1,13 @@
 use std::collections::{HashMap, HashSet};
-use std::fs;
 
-const INPUT_PATH: &str = "src/day19/input.txt";
-
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
-struct Point {
-    x: i32,
-    y: i32,
-    z: i32,
-}
-
-impl Point {
-    fn add(&self, other: &Self) -> Self {
-        Self {
-            x: self.x + other.x,
-            y: self.y + other.y,
-            z: self.z + other.z,
-        }
-    }
-
-    fn sub(&self, other: &Self) -> Self {
-        Self {
-            x: self.x - other.x,
-            y: self.y - other.y,
-            z: self.z - other.z,
-        }
-    }
-
-    fn manhattan(&self, other: &Self) -> i32 {
-        let d = self.sub(other);
-        d.x.abs() + d.y.abs() + d.z.abs()
-    }
-}
+use crate::error::{Error, Result};
+use crate::geometry::Point3;
+use crate::parse;
+use crate::solution::{Answer, Solution};
 
 #[derive(Clone, Debug)]
-struct Scanner {
-    points: Vec<Point>,
+pub struct Scanner {
+    points: Vec<Point3>,
 }
 
 impl Scanner {
@@ -45,25 +17,11 @@ impl Scanner {
 
     fn rotate(&self, rot: &Matrix) -> Self {
         Self {
-            points: self
-                .points
-                .iter()
-                .map(|v| {
-                    let p = Matrix::new_from_vector(vec![vec![v.x], vec![v.y], vec![v.z]]);
-
-                    let result = rot.mult(&p);
-
-                    Point {
-                        x: result.values[0][0],
-                        y: result.values[1][0],
-                        z: result.values[2][0],
-                    }
-                })
-                .collect(),
+            points: self.points.iter().map(|v| rot.apply(v)).collect(),
         }
     }
 
-    fn remap_origin(&self, origin_idx: usize) -> HashSet<Point> {
+    fn remap_origin(&self, origin_idx: usize) -> HashSet<Point3> {
         let mut out = HashSet::new();
 
         for i in 0..self.points.len() {
@@ -71,13 +29,44 @@ impl Scanner {
                 continue;
             }
 
-            out.insert(self.points[i].sub(&self.points[origin_idx]));
+            out.insert(self.points[i] - self.points[origin_idx]);
         }
 
         out
     }
 }
 
+/// Squared distances between the beacons seen by a scanner, which depend neither on its
+/// position nor on its orientation, with the pairs of beacons they separate.
+struct Fingerprint {
+    pairs: HashMap<i64, Vec<(usize, usize)>>,
+}
+
+impl Fingerprint {
+    fn new(scanner: &Scanner) -> Self {
+        let mut pairs: HashMap<i64, Vec<(usize, usize)>> = HashMap::new();
+
+        for i in 0..scanner.points.len() {
+            for j in i + 1..scanner.points.len() {
+                let d = scanner.points[i] - scanner.points[j];
+                let dist = [d.x, d.y, d.z].iter().map(|&c| c as i64 * c as i64).sum();
+
+                pairs.entry(dist).or_default().push((i, j));
+            }
+        }
+
+        Self { pairs }
+    }
+
+    /// Number of distances found in both fingerprints.
+    fn common(&self, other: &Self) -> usize {
+        self.pairs
+            .iter()
+            .filter_map(|(dist, pairs)| Some(pairs.len().min(other.pairs.get(dist)?.len())))
+            .sum()
+    }
+}
+
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 struct Matrix {
     values: Vec<Vec<i32>>,
@@ -102,6 +91,18 @@ impl Matrix {
         }
     }
 
+    fn apply(&self, v: &Point3) -> Point3 {
+        let p = Matrix::new_from_vector(vec![vec![v.x], vec![v.y], vec![v.z]]);
+
+        let result = self.mult(&p);
+
+        Point3::new(
+            result.values[0][0],
+            result.values[1][0],
+            result.values[2][0],
+        )
+    }
+
     fn mult(&self, other: &Self) -> Self {
         assert_eq!(self.columns, other.rows);
 
@@ -218,75 +219,87 @@ fn get_all_rotations() -> Vec<Matrix> {
     rotations.iter().cloned().collect()
 }
 
-fn parse_input(input: &str) -> Vec<Scanner> {
+fn parse_input(input: &str) -> Result<Vec<Scanner>> {
     let mut out = vec![];
     let mut scanner = Scanner::new();
 
-    for l in input.lines() {
-        if l.starts_with("---") {
+    for l in parse::lines(input) {
+        if l.text.starts_with("---") {
             continue;
         }
 
-        if l.is_empty() {
+        if l.text.is_empty() {
             out.push(scanner);
             scanner = Scanner::new();
         } else {
-            let values: Vec<i32> = l.split(',').map(|x| x.parse().unwrap()).collect();
-
-            scanner.points.push(Point {
-                x: values[0],
-                y: values[1],
-                z: values[2],
-            });
+            scanner.points.push(Point3::parse(&l, l.text)?);
         }
     }
 
     out.push(scanner);
 
-    out
+    if out[0].points.is_empty() {
+        return Err(Error::new("no beacon seen by the first scanner"));
+    }
+
+    Ok(out)
 }
 
-// Try to check if scanner_b overlaps scanner_a after one specific rotation.
+// Try to check if scanner_b overlaps scanner_a.
 //
-// At first, check if there is a couple of beacon in each scanner view that have the same relative
-// position. In this case, we might have found an overlapping beacon.
+// 12 common beacons are 66 pairs of beacons at the same distance in both scanner views, so there
+// is no overlap with fewer common distances. Otherwise, only the pairs of beacons at the same
+// distance in both views may be the same beacons: look for the rotations turning the pair of
+// scanner_b into the one of scanner_a, its beacons being in either order.
 //
 // To confirm this, remap each scanner view from one of the beacons found previously. After that,
 // check there are 12 common beacons.
 fn scanners_match(
-    scanner_a: &Scanner,
-    scanner_b: &Scanner,
+    (scanner_a, fingerprint_a): (&Scanner, &Fingerprint),
+    (scanner_b, fingerprint_b): (&Scanner, &Fingerprint),
     rotations: &[Matrix],
-) -> Option<(Scanner, Point)> {
-    for rot in rotations {
-        let rotated_b = scanner_b.rotate(rot);
-
-        for a_i in 0..scanner_a.points.len() {
-            for a_j in a_i + 1..scanner_a.points.len() {
-                let point_a = scanner_a.points[a_i].sub(&scanner_a.points[a_j]);
-
-                for b_i in 0..rotated_b.points.len() {
-                    for b_j in b_i + 1..rotated_b.points.len() {
-                        let point_b = rotated_b.points[b_i].sub(&rotated_b.points[b_j]);
-
-                        // Maybe the beacons are overlapping with the current rotation.
-                        // Check this is really the case
-                        if point_a == point_b {
-                            let a_map = scanner_a.remap_origin(a_i);
-                            let b_map = rotated_b.remap_origin(b_i);
-
-                            // Origins are not included in both remap. But we know they are already
-                            // in the possible overlap
-                            if a_map.intersection(&b_map).count() != 11 {
-                                continue;
-                            }
-
-                            // Use both "origins" to find the Scanner B position
-                            let scanner_b_pos = scanner_a.points[a_i].sub(&rotated_b.points[b_i]);
-
-                            return Some((rotated_b, scanner_b_pos));
-                        }
+) -> Option<(Scanner, Point3)> {
+    if fingerprint_a.common(fingerprint_b) < 66 {
+        return None;
+    }
+
+    for (dist, pairs_a) in &fingerprint_a.pairs {
+        let pairs_b = match fingerprint_b.pairs.get(dist) {
+            Some(pairs_b) => pairs_b,
+            None => continue,
+        };
+
+        for &(a_i, a_j) in pairs_a {
+            let point_a = scanner_a.points[a_i] - scanner_a.points[a_j];
+
+            for &(b_i, b_j) in pairs_b {
+                for rot in rotations {
+                    let point_b = rot.apply(&(scanner_b.points[b_i] - scanner_b.points[b_j]));
+
+                    // Maybe the beacons are overlapping with the current rotation.
+                    // Check this is really the case
+                    let b_origin = if point_a == point_b {
+                        b_i
+                    } else if point_a == -point_b {
+                        b_j
+                    } else {
+                        continue;
+                    };
+
+                    let rotated_b = scanner_b.rotate(rot);
+                    let a_map = scanner_a.remap_origin(a_i);
+                    let b_map = rotated_b.remap_origin(b_origin);
+
+                    // Origins are not included in both remap. But we know they are already
+                    // in the possible overlap
+                    if a_map.intersection(&b_map).count() != 11 {
+                        continue;
                     }
+
+                    // Use both "origins" to find the Scanner B position
+                    let scanner_b_pos = scanner_a.points[a_i] - rotated_b.points[b_origin];
+
+                    return Some((rotated_b, scanner_b_pos));
                 }
             }
         }
@@ -295,16 +308,18 @@ fn scanners_match(
     None
 }
 
-fn find_beacons(input: &str) -> (Vec<Point>, HashMap<usize, Point>) {
+fn find_beacons(scanners: &[Scanner]) -> Result<(Vec<Point3>, HashMap<usize, Point3>)> {
     let rotations = get_all_rotations();
     assert_eq!(rotations.len(), 24);
 
-    let mut scanners = parse_input(input);
-    let mut scanner_abspos: HashMap<usize, Point> = HashMap::new();
+    let mut scanners = scanners.to_vec();
+    // Scanners are moved and rotated once found, which leaves their fingerprint unchanged
+    let fingerprints: Vec<Fingerprint> = scanners.iter().map(Fingerprint::new).collect();
+    let mut scanner_abspos: HashMap<usize, Point3> = HashMap::new();
     let mut missing_scanners: HashSet<usize> = (1..scanners.len()).collect();
     let mut to_visit: Vec<usize> = vec![0];
 
-    let mut found_beacons: HashSet<Point> = scanners[0].points.iter().cloned().collect();
+    let mut found_beacons: HashSet<Point3> = scanners[0].points.iter().cloned().collect();
 
     while let Some(visiting_idx) = to_visit.pop() {
         let mut found_idx = HashSet::new();
@@ -312,8 +327,8 @@ fn find_beacons(input: &str) -> (Vec<Point>, HashMap<usize, Point>) {
         // Visit all scanners that have not known position
         for research_idx in &missing_scanners {
             if let Some((mut scanner_rotated, scanner_pos)) = scanners_match(
-                &scanners[visiting_idx],
-                &scanners[*research_idx],
+                (&scanners[visiting_idx], &fingerprints[visiting_idx]),
+                (&scanners[*research_idx], &fingerprints[*research_idx]),
                 &rotations,
             ) {
                 // At this point, we got the rotated scanner view, but we want to remap it from
@@ -321,7 +336,7 @@ fn find_beacons(input: &str) -> (Vec<Point>, HashMap<usize, Point>) {
                 scanner_rotated.points = scanner_rotated
                     .points
                     .iter()
-                    .map(|p| p.add(&scanner_pos))
+                    .map(|&p| p + scanner_pos)
                     .collect();
 
                 // Now all beacons have the correct coordinates, store them
@@ -346,19 +361,24 @@ fn find_beacons(input: &str) -> (Vec<Point>, HashMap<usize, Point>) {
         missing_scanners = missing_scanners.difference(&found_idx).copied().collect();
     }
 
-    assert!(missing_scanners.is_empty());
+    if let Some(idx) = missing_scanners.iter().min() {
+        return Err(Error::new(format!(
+            "scanner {} does not overlap any other scanner",
+            idx
+        )));
+    }
 
-    (found_beacons.iter().cloned().collect(), scanner_abspos)
+    Ok((found_beacons.iter().cloned().collect(), scanner_abspos))
 }
 
-fn part1(input: &str) -> usize {
-    let (beacons, _) = find_beacons(input);
-    beacons.len()
+fn part1(scanners: &[Scanner]) -> Result<usize> {
+    let (beacons, _) = find_beacons(scanners)?;
+    Ok(beacons.len())
 }
 
-fn part2(input: &str) -> i32 {
-    let (_, scanner_abspos) = find_beacons(input);
-    let scanners: Vec<&Point> = scanner_abspos.values().collect();
+fn part2(scanners: &[Scanner]) -> Result<i32> {
+    let (_, scanner_abspos) = find_beacons(scanners)?;
+    let scanners: Vec<&Point3> = scanner_abspos.values().collect();
 
     let mut max_dist = 0;
 
@@ -368,18 +388,34 @@ fn part2(input: &str) -> i32 {
         }
     }
 
-    max_dist
+    Ok(max_dist)
 }
 
-fn main() {
-    let input = fs::read_to_string(INPUT_PATH).unwrap();
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    const TITLE: &'static str = "Beacon Scanner";
+
+    type Input = Vec<Scanner>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(scanners: &Self::Input) -> Result<Answer> {
+        Ok(part1(scanners)?.into())
+    }
+
+    fn part2(scanners: &Self::Input) -> Result<Option<Answer>> {
+        Ok(Some(part2(scanners)?.into()))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::input::load;
 
     #[test]
     fn day19_matrix() {
@@ -421,7 +457,7 @@ mod tests {
         assert_eq!(rotations.len(), 24);
     }
 
-    const INPUT: &'static str = "--- scanner 0 ---
+    const INPUT: &str = "--- scanner 0 ---
 404,-588,-901
 528,-643,409
 -838,591,734
@@ -560,13 +596,42 @@ mod tests {
 
     #[test]
     fn day19_part1() {
-        assert_eq!(part1(INPUT), 79);
-        assert_eq!(part1(&fs::read_to_string(INPUT_PATH).unwrap()), 381);
+        assert_eq!(part1(&parse_input(INPUT).unwrap()), Ok(79));
+        assert_eq!(part1(&parse_input(&load(19).unwrap()).unwrap()), Ok(381));
     }
 
     #[test]
     fn day19_part2() {
-        assert_eq!(part2(INPUT), 3621);
-        assert_eq!(part2(&fs::read_to_string(INPUT_PATH).unwrap()), 12201);
+        assert_eq!(part2(&parse_input(INPUT).unwrap()), Ok(3621));
+        assert_eq!(part2(&parse_input(&load(19).unwrap()).unwrap()), Ok(12201));
+    }
+
+    #[test]
+    fn day19_fingerprint() {
+        let scanners = parse_input(INPUT).unwrap();
+        let fingerprints: Vec<_> = scanners.iter().map(Fingerprint::new).collect();
+
+        // Scanner 0 only overlaps scanner 1
+        assert!(fingerprints[0].common(&fingerprints[1]) >= 66);
+        assert!(fingerprints[0].common(&fingerprints[2]) < 66);
+        assert!(fingerprints[0].common(&fingerprints[4]) < 66);
+
+        let (_, scanner_abspos) = find_beacons(&scanners).unwrap();
+        let mut positions: Vec<_> = scanner_abspos.into_iter().collect();
+        positions.sort_unstable_by_key(|&(idx, _)| idx);
+        assert_eq!(
+            positions,
+            [
+                (1, Point3::new(68, -1246, -43)),
+                (2, Point3::new(1105, -1205, 1229)),
+                (3, Point3::new(-92, -2380, -20)),
+                (4, Point3::new(-20, -1133, 1061)),
+            ]
+        );
+    }
+
+    #[test]
+    fn day19_inputs() {
+        crate::answers::check_inputs(&Day19);
     }
 }
@@ -0,0 +1,101 @@
+use crate::error::Result;
+use crate::parse;
+use crate::solution::{Answer, Solution};
+
+#[derive(Clone, Copy, Debug)]
+pub enum Command {
+    Forward(i32),
+    Down(i32),
+    Up(i32),
+}
+
+fn parse_input(input: &str) -> Result<Vec<Command>> {
+    parse::lines(input)
+        .map(|l| {
+            let (direction, count) = l.split_once(l.text, " ")?;
+            let count = l.parse(count)?;
+
+            match direction {
+                "forward" => Ok(Command::Forward(count)),
+                "down" => Ok(Command::Down(count)),
+                "up" => Ok(Command::Up(count)),
+                _ => Err(l.error(direction, format!("unexpected direction '{}'", direction))),
+            }
+        })
+        .collect()
+}
+
+fn part1(commands: &[Command]) -> i32 {
+    let mut horizontal: i32 = 0;
+    let mut depth: i32 = 0;
+
+    for command in commands {
+        match command {
+            Command::Forward(count) => {
+                horizontal += count;
+            }
+            Command::Down(count) => {
+                depth += count;
+            }
+            Command::Up(count) => {
+                depth -= count;
+            }
+        }
+    }
+
+    horizontal * depth
+}
+
+fn part2(commands: &[Command]) -> i32 {
+    let mut horizontal: i32 = 0;
+    let mut depth: i32 = 0;
+    let mut aim: i32 = 0;
+
+    for command in commands {
+        match command {
+            Command::Forward(count) => {
+                horizontal += count;
+                depth += aim * count;
+            }
+            Command::Down(count) => {
+                aim += count;
+            }
+            Command::Up(count) => {
+                aim -= count;
+            }
+        }
+    }
+
+    horizontal * depth
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Dive!";
+
+    type Input = Vec<Command>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(commands: &Self::Input) -> Result<Answer> {
+        Ok(part1(commands).into())
+    }
+
+    fn part2(commands: &Self::Input) -> Result<Option<Answer>> {
+        Ok(Some(part2(commands).into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn day2_inputs() {
+        crate::answers::check_inputs(&Day2);
+    }
+}
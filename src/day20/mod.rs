@@ -0,0 +1,332 @@
+use std::fs;
+use std::path::PathBuf;
+
+use crate::automaton::{Automaton, Image, Rule};
+use crate::error::{Error, Result};
+use crate::grid::Grid;
+use crate::parse;
+use crate::solution::{Answer, Solution, Tool};
+use crate::tool_args::{unexpected, FrameFormat, Frames, Options};
+
+/// Output pixel for each 9-bit index read from a 3x3 square, the top left pixel being the most
+/// significant bit.
+pub type Enhancement = [bool; 512];
+
+fn automaton(enhancement: &Enhancement) -> Automaton {
+    // An enhancement holds the 512 entries of a 3x3 square
+    Automaton::new(1, Rule::Table(enhancement.to_vec())).unwrap()
+}
+
+pub struct TrenchMap {
+    enhancement: Enhancement,
+    image: Image,
+}
+
+fn parse_pixel(c: char) -> Option<bool> {
+    match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    }
+}
+
+fn parse_input(input: &str) -> Result<TrenchMap> {
+    let mut lines = parse::lines(input);
+
+    let line = lines
+        .next()
+        .ok_or_else(|| Error::new("missing enhancement algorithm"))?;
+    let enhancement =
+        parse::grid_lines(std::iter::once(line), "'#' or '.'", parse_pixel)?.remove(0);
+    let enhancement: Enhancement = enhancement.try_into().map_err(|e: Vec<_>| {
+        line.error_at(
+            line.text.len(),
+            format!("expected 512 pixels, found {}", e.len()),
+        )
+    })?;
+
+    match lines.next() {
+        Some(line) if !line.text.is_empty() => {
+            return Err(line.error_at(0, "expected a blank line"));
+        }
+        _ => {}
+    }
+
+    let grid = Grid::from_rows(parse::grid_lines(lines, "'#' or '.'", parse_pixel)?)?;
+
+    Ok(TrenchMap {
+        enhancement,
+        image: Image::from_grid(&grid),
+    })
+}
+
+fn enhance(map: &TrenchMap, rounds: usize) -> Image {
+    let automaton = automaton(&map.enhancement);
+
+    let mut image = map.image.clone();
+    for _ in 0..rounds {
+        image = automaton.step(&image);
+    }
+
+    image
+}
+
+fn solve(map: &TrenchMap, rounds: usize) -> Result<usize> {
+    enhance(map, rounds).lit().ok_or_else(|| {
+        Error::new(format!(
+            "infinitely many pixels are lit after {} rounds",
+            rounds
+        ))
+    })
+}
+
+fn enhance_tool(input: &str, args: &[String]) -> Result<String> {
+    let mut rounds = 2;
+    let mut frames = Frames::new("round", &[FrameFormat::Pbm, FrameFormat::Png]);
+    let mut image: Option<PathBuf> = None;
+    let mut crop = false;
+
+    let mut options = Options::new(args);
+    while let Some(arg) = options.arg() {
+        if frames.option(arg, &mut options)? {
+            continue;
+        }
+
+        match arg {
+            "--rounds" => rounds = options.parse(arg)?,
+            "--image" => image = Some(PathBuf::from(options.value(arg)?)),
+            "--crop" => crop = true,
+            _ => return Err(unexpected(arg)),
+        }
+    }
+    frames.start()?;
+
+    let mut map = parse_input(input)?;
+    if let Some(path) = &image {
+        let data = fs::read(path).map_err(|e| Error::new(format!("{}: {}", path.display(), e)))?;
+        map.image = Image::from_pbm(&data)?;
+    }
+
+    // Unless cropped, every round is drawn on the area the last one may cover
+    let (left, top) = map.image.origin();
+    let margin = rounds as i64;
+    let (width, height) = (
+        map.image.width() + 2 * rounds,
+        map.image.height() + 2 * rounds,
+    );
+
+    let mut current = map.image.clone();
+    let automaton = automaton(&map.enhancement);
+
+    for round in 0..=rounds {
+        if round > 0 {
+            current = automaton.step(&current);
+        }
+        if round % frames.every != 0 && round != rounds {
+            continue;
+        }
+
+        let image = if crop {
+            let mut image = current.clone();
+            image.crop();
+            image
+        } else {
+            current.window((left - margin, top - margin), width, height)
+        };
+
+        frames.write(round, &image, |format| {
+            // Image files need at least one pixel, PNG ones in particular
+            if image.width() == 0 || image.height() == 0 {
+                return Err(Error::new(format!("round {}: the image is empty", round)));
+            }
+
+            Ok(match format {
+                FrameFormat::Png => image.to_pixmap().to_png(),
+                _ => image.to_pbm(),
+            })
+        })?;
+    }
+
+    let mut out = frames.finish();
+    out += &match current.lit() {
+        Some(lit) => format!("{} pixel(s) lit after {} round(s)", lit, rounds),
+        None => format!("infinitely many pixels are lit after {} round(s)", rounds),
+    };
+
+    Ok(out)
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    const TITLE: &'static str = "Trench Map";
+
+    type Input = TrenchMap;
+
+    const TOOLS: &'static [Tool] = &[Tool {
+        name: "enhance",
+        usage: "[--rounds <N>] [--every <N>] [--format <text|pbm|png>] [--out <DIR>] \
+                [--image <PBM>] [--crop]  print every Nth round, or write it to DIR as one file \
+                per round, starting from the image of a PBM file if given. Rounds are drawn on \
+                the area of the last one unless cropped to their content",
+        run: enhance_tool,
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(map: &Self::Input) -> Result<Answer> {
+        Ok(solve(map, 2)?.into())
+    }
+
+    fn part2(map: &Self::Input) -> Result<Option<Answer>> {
+        Ok(Some(solve(map, 50)?.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::load;
+    use crate::testing::args;
+
+    const INPUT: &str = "..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..###..######.###...####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###.######.###.####...#.##.##..#..#..#####.....#.#....###..#.##......#.....#..#..#..##..#...##.######.####.####.#.#...#.......#..#.#.#...####.##.#......#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.###.##...#.....####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.......##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#
+
+#..#.
+#....
+##..#
+..#..
+..###";
+
+    #[test]
+    fn day20_part1() {
+        assert_eq!(solve(&parse_input(INPUT).unwrap(), 2).unwrap(), 35);
+        assert_eq!(
+            solve(&parse_input(&load(20).unwrap()).unwrap(), 2).unwrap(),
+            5306
+        );
+    }
+
+    #[test]
+    fn day20_part2() {
+        assert_eq!(solve(&parse_input(INPUT).unwrap(), 50).unwrap(), 3351);
+        assert_eq!(
+            solve(&parse_input(&load(20).unwrap()).unwrap(), 50).unwrap(),
+            17497
+        );
+    }
+
+    #[test]
+    fn day20_background() {
+        let mut map = parse_input(INPUT).unwrap();
+
+        // A lit background only stays lit if the last enhancement pixel is lit too
+        map.enhancement[0] = true;
+        map.enhancement[511] = true;
+        let image = enhance(&map, 2);
+        assert!(image.background());
+        assert_eq!(
+            solve(&map, 2).unwrap_err().to_string(),
+            "infinitely many pixels are lit after 2 rounds"
+        );
+
+        map.enhancement[511] = false;
+        assert!(enhance(&map, 1).background());
+        assert!(!enhance(&map, 2).background());
+
+        // Keeping the center pixel, the image never grows
+        map.enhancement = [false; 512];
+        for (index, pixel) in map.enhancement.iter_mut().enumerate() {
+            *pixel = index & 0b000_010_000 != 0;
+        }
+        let image = enhance(&map, 5000);
+        assert_eq!(image.to_string(), map.image.to_string());
+        assert_eq!(image.lit(), Some(10));
+    }
+
+    #[test]
+    fn day20_pbm() {
+        let image = parse_input(INPUT).unwrap().image;
+        let pbm = image.to_pbm();
+
+        assert_eq!(pbm, b"P4\n5 5\n\x90\x80\xc8\x20\x38");
+        assert_eq!(Image::from_pbm(&pbm).unwrap(), image);
+
+        let plain = b"P1\n# comment\n5 5\n10010\n1 0 0 0 0\n11001\n00100\n00111\n";
+        assert_eq!(Image::from_pbm(plain).unwrap(), image);
+
+        let err = |data: &[u8]| Image::from_pbm(data).unwrap_err().to_string();
+        assert_eq!(
+            err(b"P6\n1 1\n"),
+            "PBM file: expected 'P1' or 'P4', found 'P6'"
+        );
+        assert_eq!(err(b"P4\n2 x\n"), "PBM file: invalid height 'x'");
+        assert_eq!(err(b"P4\n9 2\n\0\0\0"), "PBM file: truncated pixel data");
+        assert_eq!(
+            err(b"P4\n1 1\n\0\0"),
+            "PBM file: unexpected data after the pixels"
+        );
+        assert_eq!(
+            err(b"P1 1 1 0 1"),
+            "PBM file: unexpected data after the pixels"
+        );
+
+        // Oversized headers are rejected before allocating the image
+        assert_eq!(
+            err(b"P4\n4294967296 4294967296\n\0"),
+            "PBM file: 4294967296x4294967296 pixels is too large"
+        );
+        assert_eq!(
+            err(b"P4\n100000 100000\n\0"),
+            "PBM file: truncated pixel data"
+        );
+        assert_eq!(
+            err(b"P1 2 1 0 2"),
+            "PBM file: expected '0' or '1', found '2'"
+        );
+
+        // The window may reach beyond the stored area, the image crops back to its content
+        let mut window = image.window((-1, -2), 7, 8);
+        assert_eq!(window.to_string().lines().nth(2), Some(".#..#.."));
+        window.crop();
+        assert_eq!(window, image);
+    }
+
+    #[test]
+    fn day20_enhance_tool() {
+        let out = enhance_tool(INPUT, &args(&["--rounds", "1", "--crop"])).unwrap();
+        assert_eq!(
+            out,
+            "round 0:\n#..#.\n#....\n##..#\n..#..\n..###\n\n\
+             round 1:\n.##.##.\n#..#.#.\n##.#..#\n####..#\n.#..##.\n..##..#\n...#.#.\n\n\
+             24 pixel(s) lit after 1 round(s)"
+        );
+
+        let out = enhance_tool(INPUT, &args(&["--rounds", "2", "--every", "5"])).unwrap();
+        assert!(out.starts_with("round 0:\n.........\n.........\n..#..#...\n"));
+        assert!(out.ends_with("35 pixel(s) lit after 2 round(s)"));
+
+        // Cropping a dark image leaves no pixel to write
+        let dark = format!("{}{}", ".".repeat(512), &INPUT[512..]);
+        let dir = std::env::temp_dir().join(format!("aoc2021-day20-{}", std::process::id()));
+        let out = dir.to_str().unwrap();
+        assert_eq!(
+            enhance_tool(
+                &dark,
+                &args(&["--rounds", "1", "--crop", "--format", "png", "--out", out])
+            )
+            .unwrap_err()
+            .to_string(),
+            "round 1: the image is empty"
+        );
+        assert!(dir.join("round-00000.png").is_file());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn day20_inputs() {
+        crate::answers::check_inputs(&Day20);
+    }
+}
@@ -0,0 +1,627 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::error::{Error, Result};
+use crate::parse;
+use crate::solution::{Answer, Solution, Tool};
+use crate::tool_args::{invalid, unexpected, Options};
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Player {
+    pos: u64,
+    score: u64,
+}
+
+impl Player {
+    fn new(pos: u64) -> Self {
+        Self { pos, score: 0 }
+    }
+
+    /// Move `distance` spaces forward on a board of `board` spaces, and score the new space.
+    fn advance(&self, distance: u64, board: u64) -> Self {
+        let pos = (self.pos + distance) % board;
+
+        Self {
+            pos,
+            score: self.score + pos + 1,
+        }
+    }
+}
+
+/// Parameters of a game: players take turns rolling the die `rolls` times, and moving forward
+/// by the total around a circular board of `board` spaces, numbered from 1.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct GameRules {
+    pub board: u64,
+    /// Number of faces of the die, numbered from 1.
+    pub faces: u64,
+    pub rolls: u64,
+    /// Score a player must reach to win.
+    pub target: u64,
+    pub players: usize,
+}
+
+impl GameRules {
+    /// The practice game of part 1, with a deterministic 100-sided die.
+    pub const PRACTICE: Self = Self {
+        board: 10,
+        faces: 100,
+        rolls: 3,
+        target: 1000,
+        players: 2,
+    };
+
+    /// The game of part 2, with the 3-sided Dirac die.
+    pub const DIRAC: Self = Self {
+        target: 21,
+        faces: 3,
+        ..Self::PRACTICE
+    };
+}
+
+fn parse_players(input: &str, rules: &GameRules) -> Result<Vec<Player>> {
+    let players = parse::lines(input)
+        .map(|line| {
+            let prefix = format!("Player {} starting position: ", line.number);
+            let pos = line.strip_prefix(line.text, &prefix)?;
+
+            match line.parse::<u64>(pos)? {
+                p if (1..=rules.board).contains(&p) => Ok(Player::new(p - 1)),
+                _ => Err(line.error(
+                    pos,
+                    format!("position must be between 1 and {}", rules.board),
+                )),
+            }
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    if players.len() != rules.players {
+        return Err(Error::new(format!(
+            "expected {} players, found {}",
+            rules.players,
+            players.len()
+        )));
+    }
+
+    Ok(players)
+}
+
+fn parse_input(input: &str) -> Result<Vec<Player>> {
+    parse_players(input, &GameRules::PRACTICE)
+}
+
+/// Die driving a game.
+pub trait Die {
+    /// Roll the die once: each face it shows, with the number of universes it shows it in.
+    fn roll(&mut self) -> Vec<(u64, u64)>;
+}
+
+/// Die rolling 1, 2, and so on up to its number of faces, then 1 again.
+pub struct DeterministicDie {
+    faces: u64,
+    next: u64,
+}
+
+impl DeterministicDie {
+    pub fn new(faces: u64) -> Self {
+        Self { faces, next: 0 }
+    }
+}
+
+impl Die for DeterministicDie {
+    fn roll(&mut self) -> Vec<(u64, u64)> {
+        let face = self.next + 1;
+        self.next = face % self.faces;
+
+        vec![(face, 1)]
+    }
+}
+
+/// Die rolling pseudo random faces, always the same ones for a given seed.
+pub struct RandomDie {
+    faces: u64,
+    state: u64,
+}
+
+impl RandomDie {
+    pub fn new(faces: u64, seed: u64) -> Self {
+        // Scramble the seed with a splitmix64 step, so that close seeds start far apart
+        let mut state = seed.wrapping_add(0x9e3779b97f4a7c15);
+        state = (state ^ (state >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        state = (state ^ (state >> 27)).wrapping_mul(0x94d049bb133111eb);
+        state ^= state >> 31;
+
+        // Xorshift gets stuck on 0
+        if state == 0 {
+            state = 0x9e3779b97f4a7c15;
+        }
+
+        Self { faces, state }
+    }
+}
+
+impl Die for RandomDie {
+    fn roll(&mut self) -> Vec<(u64, u64)> {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+
+        vec![(self.state % self.faces + 1, 1)]
+    }
+}
+
+/// Dirac die, splitting the universe in one copy per face each time it is rolled.
+pub struct QuantumDie {
+    faces: u64,
+}
+
+impl QuantumDie {
+    pub fn new(faces: u64) -> Self {
+        Self { faces }
+    }
+}
+
+impl Die for QuantumDie {
+    fn roll(&mut self) -> Vec<(u64, u64)> {
+        (1..=self.faces).map(|face| (face, 1)).collect()
+    }
+}
+
+/// Turn of a game played in a single universe.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Turn {
+    /// Index of the player, from 0.
+    pub player: usize,
+    pub rolls: Vec<u64>,
+    /// Space the player moved to, from 1.
+    pub space: u64,
+    pub score: u64,
+}
+
+impl fmt::Display for Turn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rolls: Vec<String> = self.rolls.iter().map(u64::to_string).collect();
+
+        write!(
+            f,
+            "Player {} rolls {} and moves to space {} for a total score of {}.",
+            self.player + 1,
+            rolls.join("+"),
+            self.space,
+            self.score
+        )
+    }
+}
+
+/// End of a game.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Game {
+    /// Universes won by each player.
+    pub wins: Vec<u128>,
+    /// Number of times the die was rolled, in the longest universe.
+    pub rolls: u64,
+    /// Turns played, as long as the game stays in a single universe.
+    pub turns: Vec<Turn>,
+    /// Players at the end of the game, if it is played in a single universe.
+    pub players: Option<Vec<Player>>,
+}
+
+impl Game {
+    /// Final scores of the players, if the game is played in a single universe.
+    pub fn scores(&self) -> Option<Vec<u64>> {
+        let players = self.players.as_ref()?;
+
+        Some(players.iter().map(|p| p.score).collect())
+    }
+}
+
+/// Play a game until every universe has a winner, universes in which the players end up at
+/// the same spaces with the same scores being counted together.
+pub fn play(players: &[Player], rules: &GameRules, die: &mut impl Die) -> Game {
+    let mut universes: HashMap<Vec<Player>, u128> = HashMap::from([(players.to_vec(), 1)]);
+    let mut game = Game {
+        wins: vec![0; players.len()],
+        rolls: 0,
+        turns: vec![],
+        players: None,
+    };
+
+    for mover in (0..players.len()).cycle() {
+        if universes.is_empty() {
+            break;
+        }
+
+        let faces: Vec<_> = (0..rules.rolls).map(|_| die.roll()).collect();
+        game.rolls += rules.rolls;
+
+        let single = universes.len() == 1
+            && universes.values().all(|&count| count == 1)
+            && faces.iter().all(|f| f.len() == 1);
+
+        let mut next = HashMap::new();
+        for (state, count) in universes {
+            for &(distance, ways) in &combine(&faces) {
+                let moved = state[mover].advance(distance, rules.board);
+                let count = count * ways as u128;
+
+                if single {
+                    game.turns.push(Turn {
+                        player: mover,
+                        rolls: faces.iter().map(|f| f[0].0).collect(),
+                        space: moved.pos + 1,
+                        score: moved.score,
+                    });
+                }
+
+                let mut state = state.clone();
+                state[mover] = moved;
+
+                if moved.score >= rules.target {
+                    game.wins[mover] += count;
+                    if single {
+                        game.players = Some(state);
+                    }
+                } else {
+                    *next.entry(state).or_insert(0) += count;
+                }
+            }
+        }
+
+        universes = next;
+    }
+
+    game
+}
+
+fn part1(players: &[Player]) -> Result<u64> {
+    let rules = GameRules::PRACTICE;
+    let game = play(players, &rules, &mut DeterministicDie::new(rules.faces));
+
+    let scores = game
+        .scores()
+        .ok_or_else(|| Error::new("the practice game split into several universes"))?;
+
+    Ok(scores.iter().min().unwrap() * game.rolls)
+}
+
+/// Number of ways to get each total from faces rolled one after the other.
+fn combine(faces: &[Vec<(u64, u64)>]) -> Vec<(u64, u64)> {
+    let mut totals = HashMap::from([(0, 1)]);
+
+    for roll in faces {
+        let mut next = HashMap::new();
+        for (total, count) in totals {
+            for &(face, ways) in roll {
+                *next.entry(total + face).or_insert(0) += count * ways;
+            }
+        }
+
+        totals = next;
+    }
+
+    let mut v: Vec<(u64, u64)> = totals.into_iter().collect();
+    v.sort_unstable();
+
+    v
+}
+
+/// Number of ways to get each total when rolling `die` `rolls` times.
+fn compute_rolls(die: &mut impl Die, rolls: u64) -> Vec<(u64, u64)> {
+    let faces: Vec<_> = (0..rolls).map(|_| die.roll()).collect();
+
+    combine(&faces)
+}
+
+/// Count the universes won by each player with the quantum die, a quicker [`play`] for it.
+///
+/// The outcome of a game only depends on the positions and scores of the players and on who
+/// plays next, so it is computed once for each of these states.
+pub fn dirac_wins(players: &[Player], rules: &GameRules) -> Vec<u128> {
+    // Universes won by each player, the first one being about to play
+    fn wins(
+        players: &[Player],
+        rules: &GameRules,
+        totals: &[(u64, u64)],
+        cache: &mut HashMap<Vec<Player>, Vec<u128>>,
+    ) -> Vec<u128> {
+        if let Some(wins) = cache.get(players) {
+            return wins.clone();
+        }
+
+        let count = players.len();
+        let mut total = vec![0; count];
+        for &(distance, universes) in totals {
+            let moved = players[0].advance(distance, rules.board);
+
+            if moved.score >= rules.target {
+                total[0] += universes as u128;
+            } else {
+                // The next player plays first, the one who just moved last
+                let mut next = players[1..].to_vec();
+                next.push(moved);
+
+                for (idx, w) in wins(&next, rules, totals, cache).into_iter().enumerate() {
+                    total[(idx + 1) % count] += universes as u128 * w;
+                }
+            }
+        }
+
+        cache.insert(players.to_vec(), total.clone());
+        total
+    }
+
+    let totals = compute_rolls(&mut QuantumDie::new(rules.faces), rules.rolls);
+    let mut cache = HashMap::new();
+
+    wins(players, rules, &totals, &mut cache)
+}
+
+fn part2(players: &[Player]) -> u128 {
+    dirac_wins(players, &GameRules::DIRAC)
+        .into_iter()
+        .max()
+        .unwrap()
+}
+
+fn play_tool(input: &str, args: &[String]) -> Result<String> {
+    let mut die = "deterministic";
+    let mut seed = 21;
+    let mut settings: Vec<(&str, u64)> = vec![];
+
+    let mut options = Options::new(args);
+    while let Some(arg) = options.arg() {
+        match arg {
+            "--die" => die = options.value(arg)?,
+            "--seed" => seed = options.parse(arg)?,
+            // Boards and dice need at least one space and one face, other settings may be 0
+            "--board" | "--faces" => settings.push((arg, options.positive(arg)?)),
+            "--rolls" | "--target" | "--players" => settings.push((arg, options.parse(arg)?)),
+            _ => return Err(unexpected(arg)),
+        }
+    }
+
+    // The quantum die plays the game of part 2 unless told otherwise, the others the practice
+    // game of part 1
+    let mut rules = match die {
+        "quantum" => GameRules::DIRAC,
+        _ => GameRules::PRACTICE,
+    };
+    for (arg, n) in settings {
+        match arg {
+            "--board" => rules.board = n,
+            "--faces" => rules.faces = n,
+            "--rolls" => rules.rolls = n,
+            "--target" => rules.target = n,
+            _ => rules.players = n as usize,
+        }
+    }
+
+    let players = parse_players(input, &rules)?;
+    let game = match die {
+        "deterministic" => play(&players, &rules, &mut DeterministicDie::new(rules.faces)),
+        "random" => play(&players, &rules, &mut RandomDie::new(rules.faces, seed)),
+        "quantum" => play(&players, &rules, &mut QuantumDie::new(rules.faces)),
+        _ => return Err(invalid("--die", die)),
+    };
+
+    let mut out = String::new();
+    for turn in &game.turns {
+        out += &format!("{}\n", turn);
+    }
+    for (player, wins) in game.wins.iter().enumerate() {
+        out += &format!("player {} wins in {} universe(s)\n", player + 1, wins);
+    }
+    out += &format!("the die was rolled {} times", game.rolls);
+
+    Ok(out)
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    const TITLE: &'static str = "Dirac Dice";
+
+    type Input = Vec<Player>;
+
+    const TOOLS: &'static [Tool] = &[Tool {
+        name: "play",
+        usage: "[--die <deterministic|random|quantum>] [--seed <N>] [--board <N>] \
+                [--faces <N>] [--rolls <N>] [--target <N>] [--players <N>]  play a game, \
+                logging its turns as long as it stays in a single universe",
+        run: play_tool,
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(players: &Self::Input) -> Result<Answer> {
+        Ok(part1(players)?.into())
+    }
+
+    fn part2(players: &Self::Input) -> Result<Option<Answer>> {
+        Ok(Some(part2(players).into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::load;
+    use crate::testing::args;
+
+    const INPUT: &str = "Player 1 starting position: 4
+Player 2 starting position: 8";
+
+    #[test]
+    fn day21_part1() {
+        assert_eq!(part1(&parse_input(INPUT).unwrap()), Ok(739785));
+        assert_eq!(part1(&parse_input(&load(21).unwrap()).unwrap()), Ok(797160));
+    }
+
+    #[test]
+    fn day21_part2() {
+        assert_eq!(part2(&parse_input(INPUT).unwrap()), 444356092776315);
+        assert_eq!(
+            part2(&parse_input(&load(21).unwrap()).unwrap()),
+            27464148626406
+        );
+    }
+
+    #[test]
+    fn day21_rules() {
+        let players = parse_input(INPUT).unwrap();
+
+        let game = play(
+            &players,
+            &GameRules::PRACTICE,
+            &mut DeterministicDie::new(100),
+        );
+        assert_eq!(game.scores(), Some(vec![1000, 745]));
+        assert_eq!((game.wins, game.rolls), (vec![1, 0], 993));
+
+        let dirac = |target| {
+            let rules = GameRules {
+                target,
+                ..GameRules::DIRAC
+            };
+            dirac_wins(&players, &rules)
+        };
+
+        // The first player wins at once in every universe
+        assert_eq!(dirac(1), vec![27, 0]);
+        assert_eq!(dirac(21), vec![444356092776315, 341960390180808]);
+
+        // Universes multiply by 27 each turn, so there are more than a u64 can count
+        assert!(dirac(50).iter().sum::<u128>() > u64::MAX as u128);
+
+        // Three players on a smaller board, rolling a 2-sided die twice
+        let rules = GameRules {
+            board: 5,
+            faces: 2,
+            rolls: 2,
+            target: 10,
+            players: 3,
+        };
+        let players = parse_players(
+            "Player 1 starting position: 1\n\
+             Player 2 starting position: 3\n\
+             Player 3 starting position: 5",
+            &rules,
+        )
+        .unwrap();
+        assert_eq!(
+            compute_rolls(&mut QuantumDie::new(2), 2),
+            vec![(2, 1), (3, 2), (4, 1)]
+        );
+        assert_eq!(
+            play(&players, &rules, &mut DeterministicDie::new(2)).wins,
+            vec![1, 0, 0]
+        );
+
+        let wins = dirac_wins(&players, &rules);
+        assert_eq!(wins.len(), 3);
+        assert!(wins.iter().all(|&w| w > 0));
+        assert!(parse_players(INPUT, &rules).is_err());
+    }
+
+    #[test]
+    fn day21_dice() {
+        let players = parse_input(INPUT).unwrap();
+        let rules = GameRules::PRACTICE;
+
+        let game = play(&players, &rules, &mut DeterministicDie::new(100));
+        assert_eq!(
+            game.turns[..2]
+                .iter()
+                .map(Turn::to_string)
+                .collect::<Vec<_>>(),
+            [
+                "Player 1 rolls 1+2+3 and moves to space 10 for a total score of 10.",
+                "Player 2 rolls 4+5+6 and moves to space 3 for a total score of 3.",
+            ]
+        );
+        assert_eq!(
+            game.turns.last().unwrap().to_string(),
+            "Player 1 rolls 91+92+93 and moves to space 10 for a total score of 1000."
+        );
+
+        // The deterministic die wraps after its last face
+        let mut die = DeterministicDie::new(3);
+        let faces: Vec<_> = (0..4).map(|_| die.roll()[0].0).collect();
+        assert_eq!(faces, [1, 2, 3, 1]);
+
+        // A seeded die always plays the same game, in a single universe
+        let random = |seed| play(&players, &rules, &mut RandomDie::new(100, seed));
+        assert_eq!(random(7), random(7));
+        assert_ne!(random(6).turns[0].rolls, random(7).turns[0].rolls);
+        assert_ne!(random(0).turns[0].rolls, random(1).turns[0].rolls);
+        assert_eq!(random(7).wins.iter().sum::<u128>(), 1);
+        assert!(random(7)
+            .scores()
+            .unwrap()
+            .contains(&random(7).turns.last().unwrap().score));
+        assert!(random(7)
+            .turns
+            .iter()
+            .flat_map(|t| &t.rolls)
+            .all(|r| (1..=100).contains(r)));
+
+        // The quantum die splits the game, so its turns are not logged
+        let rules = GameRules::DIRAC;
+        let game = play(&players, &rules, &mut QuantumDie::new(3));
+        assert_eq!(game.wins, dirac_wins(&players, &rules));
+        assert!(game.turns.is_empty());
+        assert_eq!(game.scores(), None);
+    }
+
+    #[test]
+    fn day21_play_tool() {
+        let tool = |a: &[&str]| play_tool(INPUT, &args(a));
+
+        let seeded = tool(&["--die", "random", "--seed", "0"]).unwrap();
+        assert_eq!(seeded, tool(&["--die", "random", "--seed", "0"]).unwrap());
+        assert!(seeded.ends_with("times"));
+
+        assert_eq!(
+            tool(&["--board", "0"]).unwrap_err().to_string(),
+            "invalid value '0' for '--board'"
+        );
+        assert_eq!(
+            tool(&["--faces", "0"]).unwrap_err().to_string(),
+            "invalid value '0' for '--faces'"
+        );
+        assert_eq!(
+            tool(&["--seed"]).unwrap_err().to_string(),
+            "missing value for '--seed'"
+        );
+    }
+
+    #[test]
+    fn day21_errors() {
+        let err = |input| parse_input(input).unwrap_err().to_string();
+
+        assert_eq!(
+            err("Player 1 starting position: 4\nPlayer 1 starting position: 8"),
+            "line 2, column 1: expected 'Player 2 starting position: '"
+        );
+        assert_eq!(
+            err("Player 1 starting position: 11"),
+            "line 1, column 29: position must be between 1 and 10"
+        );
+        assert_eq!(
+            err("Player 1 starting position: x"),
+            "line 1, column 29: expected u64, found 'x'"
+        );
+        assert_eq!(
+            err("Player 1 starting position: 4"),
+            "expected 2 players, found 1"
+        );
+    }
+
+    #[test]
+    fn day21_inputs() {
+        crate::answers::check_inputs(&Day21);
+    }
+}
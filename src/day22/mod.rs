@@ -0,0 +1,412 @@
+use crate::cuboid::{Cuboid, CuboidSet, Range};
+use crate::error::{Error, Result};
+use crate::geometry::Point3;
+use crate::parse::{self, Line};
+use crate::solution::{Answer, Solution, Tool};
+
+/// Initialization procedure region of part 1, -50..50 on every axis.
+const INIT_REGION: Cuboid = Cuboid::new(
+    Range::new(-50, 50),
+    Range::new(-50, 50),
+    Range::new(-50, 50),
+);
+
+#[derive(Debug)]
+pub struct Step {
+    pub on: bool,
+    pub cuboid: Cuboid,
+}
+
+fn parse_range<'a>(line: &Line<'a>, input: &'a str, axis: &str) -> Result<Range> {
+    let range = line.strip_prefix(input, &format!("{}=", axis))?;
+    let (left, right) = line.split_once(range, "..")?;
+
+    let (lowest, highest) = (line.parse(left)?, line.parse(right)?);
+    if lowest > highest {
+        return Err(line.error(input, "range is reversed"));
+    }
+
+    Ok(Range::new(lowest, highest))
+}
+
+fn parse_input(input: &str) -> Result<Vec<Step>> {
+    parse::lines(input)
+        .map(|line| {
+            let (state, ranges) = line.split_once(line.text, " ")?;
+            let on = match state {
+                "on" => true,
+                "off" => false,
+                _ => {
+                    return Err(
+                        line.error(state, format!("expected 'on' or 'off', found '{}'", state))
+                    )
+                }
+            };
+
+            let ranges: Vec<_> = ranges.split(',').collect();
+            if ranges.len() != 3 {
+                return Err(line.error(
+                    ranges[0],
+                    format!("expected 3 ranges, found {}", ranges.len()),
+                ));
+            }
+
+            let x = parse_range(&line, ranges[0], "x")?;
+            let y = parse_range(&line, ranges[1], "y")?;
+            let z = parse_range(&line, ranges[2], "z")?;
+
+            Ok(Step {
+                on,
+                cuboid: Cuboid::new(x, y, z),
+            })
+        })
+        .collect()
+}
+
+/// Number of cubes lit after each step, only counting the ones in `region` if there is one.
+///
+/// Instead of tracking cubes, keep a list of cuboids counted positively or negatively, by
+/// inclusion-exclusion: each step cancels its overlap with every cuboid of the list, then adds
+/// itself if it turns cubes on.
+fn lit_volumes(steps: &[Step], region: Option<&Cuboid>) -> Vec<i128> {
+    let mut signed: Vec<(Cuboid, i128)> = vec![];
+    let mut volume = 0;
+
+    steps
+        .iter()
+        .map(|step| {
+            let cuboid = match region {
+                Some(region) => match step.cuboid.intersect(region) {
+                    Some(cuboid) => cuboid,
+                    None => return volume,
+                },
+                None => step.cuboid,
+            };
+
+            let mut added: Vec<_> = signed
+                .iter()
+                .filter_map(|(other, sign)| Some((cuboid.intersect(other)?, -sign)))
+                .collect();
+            if step.on {
+                added.push((cuboid, 1));
+            }
+
+            volume += added
+                .iter()
+                .map(|(c, sign)| c.volume() * sign)
+                .sum::<i128>();
+            signed.extend(added);
+
+            volume
+        })
+        .collect()
+}
+
+/// Number of cubes lit at the end of the reboot.
+fn reboot(steps: &[Step], region: Option<&Cuboid>) -> i128 {
+    lit_volumes(steps, region).last().copied().unwrap_or(0)
+}
+
+/// Whether each step is fully covered by the steps after it, so it has no effect on the end
+/// result.
+fn shadowed(steps: &[Step], region: Option<&Cuboid>) -> Vec<bool> {
+    let mut later = CuboidSet::new();
+    let mut shadowed = vec![false; steps.len()];
+
+    for (idx, step) in steps.iter().enumerate().rev() {
+        let cuboid = match region {
+            Some(region) => step.cuboid.intersect(region),
+            None => Some(step.cuboid),
+        };
+
+        // Steps out of the region change nothing either
+        shadowed[idx] = match cuboid {
+            Some(cuboid) => {
+                let shadow = [cuboid].into_iter().collect::<CuboidSet>();
+                let hidden = shadow.difference(&later).is_empty();
+                later.insert(cuboid);
+                hidden
+            }
+            None => true,
+        };
+    }
+
+    shadowed
+}
+
+/// Cubes lit after the first `count` steps.
+pub fn cuboids_after(steps: &[Step], count: usize) -> CuboidSet {
+    let mut set = CuboidSet::new();
+
+    for step in &steps[..count.min(steps.len())] {
+        if step.on {
+            set.insert(step.cuboid);
+        } else {
+            set.remove(&step.cuboid);
+        }
+    }
+
+    set
+}
+
+/// Whether `cube` is lit after the first `count` steps: the last of them covering it decides.
+pub fn is_on_after(steps: &[Step], count: usize, cube: Point3) -> bool {
+    steps[..count.min(steps.len())]
+        .iter()
+        .rev()
+        .find(|s| s.cuboid.contains(cube))
+        .is_some_and(|s| s.on)
+}
+
+fn replay_tool(input: &str, args: &[String]) -> Result<String> {
+    let region = match args {
+        [] => None,
+        [arg] if arg == "--init" => Some(&INIT_REGION),
+        _ => return Err(Error::new("expected no argument or '--init'")),
+    };
+
+    let steps = parse_input(input)?;
+    let volumes = lit_volumes(&steps, region);
+    let shadowed = shadowed(&steps, region);
+
+    let mut out = String::new();
+    let mut before = 0;
+
+    for (idx, step) in steps.iter().enumerate() {
+        out += &format!(
+            "step {:4}  {:<3}  {}  {:+}  lit {}{}\n",
+            idx + 1,
+            if step.on { "on" } else { "off" },
+            step.cuboid,
+            volumes[idx] - before,
+            volumes[idx],
+            if shadowed[idx] { "  (shadowed)" } else { "" }
+        );
+
+        before = volumes[idx];
+    }
+
+    out += &format!(
+        "{} step(s), {} shadowed by later ones, {} cubes lit",
+        steps.len(),
+        shadowed.iter().filter(|&&s| s).count(),
+        before
+    );
+
+    Ok(out)
+}
+
+fn query_tool(input: &str, args: &[String]) -> Result<String> {
+    let steps = parse_input(input)?;
+
+    let (cube, count) = match args {
+        [cube] => (cube, steps.len()),
+        [cube, count] => {
+            let count = count
+                .parse()
+                .map_err(|_| Error::new(format!("expected a step number, found '{}'", count)))?;
+            (cube, count)
+        }
+        _ => return Err(Error::new("expected a cube and an optional step number")),
+    };
+    let cube: Point3 = cube.parse()?;
+
+    let state = if is_on_after(&steps, count, cube) {
+        "on"
+    } else {
+        "off"
+    };
+
+    Ok(format!(
+        "{} is {} after step {}",
+        cube,
+        state,
+        count.min(steps.len())
+    ))
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+    const TITLE: &'static str = "Reactor Reboot";
+
+    type Input = Vec<Step>;
+
+    const TOOLS: &'static [Tool] = &[
+        Tool {
+            name: "replay",
+            usage: "[--init]  lit volume after each step, its change and whether later steps \
+                    shadow it, only counting the initialization region with '--init'",
+            run: replay_tool,
+        },
+        Tool {
+            name: "query",
+            usage: "<X,Y,Z> [<STEP>]  whether a cube is on after a step, the last one by default",
+            run: query_tool,
+        },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(steps: &Self::Input) -> Result<Answer> {
+        Ok(reboot(steps, Some(&INIT_REGION)).into())
+    }
+
+    fn part2(steps: &Self::Input) -> Result<Option<Answer>> {
+        Ok(Some(reboot(steps, None).into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::load;
+    use crate::testing::args;
+
+    const INPUT: &str = "on x=-20..26,y=-36..17,z=-47..7
+on x=-20..33,y=-21..23,z=-26..28
+on x=-22..28,y=-29..23,z=-38..16
+on x=-46..7,y=-6..46,z=-50..-1
+on x=-49..1,y=-3..46,z=-24..28
+on x=2..47,y=-22..22,z=-23..27
+on x=-27..23,y=-28..26,z=-21..29
+on x=-39..5,y=-6..47,z=-3..44
+on x=-30..21,y=-8..43,z=-13..34
+on x=-22..26,y=-27..20,z=-29..19
+off x=-48..-32,y=26..41,z=-47..-37
+on x=-12..35,y=6..50,z=-50..-2
+off x=-48..-32,y=-32..-16,z=-15..-5
+on x=-18..26,y=-33..15,z=-7..46
+off x=-40..-22,y=-38..-28,z=23..41
+on x=-16..35,y=-41..10,z=-47..6
+off x=-32..-23,y=11..30,z=-14..3
+on x=-49..-5,y=-3..45,z=-29..18
+off x=18..30,y=-20..-8,z=-3..13
+on x=-41..9,y=-7..43,z=-33..15
+on x=-54112..-39298,y=-85059..-49293,z=-27449..7877
+on x=967..23432,y=45373..81175,z=27513..53682";
+
+    #[test]
+    fn day22_part1() {
+        assert_eq!(
+            reboot(&parse_input(INPUT).unwrap(), Some(&INIT_REGION)),
+            590784
+        );
+        assert_eq!(
+            reboot(
+                &parse_input(&load(22).unwrap()).unwrap(),
+                Some(&INIT_REGION)
+            ),
+            582644
+        );
+    }
+
+    #[test]
+    fn day22_part2() {
+        let steps = parse_input(
+            "on x=0..9,y=0..9,z=0..9\noff x=5..14,y=5..14,z=5..14\non x=8..8,y=8..8,z=8..8",
+        )
+        .unwrap();
+        assert_eq!(reboot(&steps, None), 1000 - 125 + 1);
+
+        assert_eq!(
+            reboot(&parse_input(&load(22).unwrap()).unwrap(), None),
+            1263804707062415
+        );
+    }
+
+    #[test]
+    fn day22_cuboids() {
+        let steps = parse_input(INPUT).unwrap();
+
+        let lit = cuboids_after(&steps, 20);
+        assert_eq!(
+            lit.intersection(&[INIT_REGION].into_iter().collect())
+                .volume(),
+            590784
+        );
+        assert_eq!(lit.volume(), reboot(&steps[..20], None));
+        assert_eq!(
+            cuboids_after(&steps, steps.len()).volume(),
+            reboot(&steps, None)
+        );
+
+        // Lit by step 4, turned off by step 11
+        let cube = Point3::new(-40, 30, -40);
+        assert!(!is_on_after(&steps, 3, cube));
+        assert!(is_on_after(&steps, 10, cube));
+        assert!(!is_on_after(&steps, 11, cube));
+        let cube = Point3::new(0, 0, 0);
+        assert!(is_on_after(&steps, 1, cube));
+        assert_eq!(lit.contains(cube), is_on_after(&steps, 20, cube));
+    }
+
+    #[test]
+    fn day22_replay() {
+        let steps = parse_input(
+            "on x=0..9,y=0..9,z=0..9\non x=2..3,y=2..3,z=6..7\noff x=0..9,y=0..9,z=5..9\non x=60..60,y=0..0,z=0..0",
+        )
+        .unwrap();
+
+        assert_eq!(lit_volumes(&steps, None), vec![1000, 1000, 500, 501]);
+        assert_eq!(
+            lit_volumes(&steps, Some(&INIT_REGION)),
+            vec![1000, 1000, 500, 500]
+        );
+        assert_eq!(shadowed(&steps, None), vec![false, true, false, false]);
+        assert_eq!(
+            shadowed(&steps, Some(&INIT_REGION)),
+            vec![false, true, false, true]
+        );
+
+        let input = "on x=0..9,y=0..9,z=0..9\noff x=0..9,y=0..9,z=5..9";
+        assert_eq!(
+            replay_tool(input, &[]).unwrap(),
+            "step    1  on   x=0..9,y=0..9,z=0..9  +1000  lit 1000
+step    2  off  x=0..9,y=0..9,z=5..9  -500  lit 500
+2 step(s), 0 shadowed by later ones, 500 cubes lit"
+        );
+        assert_eq!(
+            query_tool(input, &args(&["1,1,7", "1"])).unwrap(),
+            "1,1,7 is on after step 1"
+        );
+        assert_eq!(
+            query_tool(input, &args(&["1,1,7"])).unwrap(),
+            "1,1,7 is off after step 2"
+        );
+    }
+
+    #[test]
+    fn day22_errors() {
+        let err = |input| parse_input(input).unwrap_err().to_string();
+
+        assert_eq!(
+            err("on x=10..12,y=10..12,z=10..12\nflip x=1..2,y=1..2,z=1..2"),
+            "line 2, column 1: expected 'on' or 'off', found 'flip'"
+        );
+        assert_eq!(
+            err("on x=10..12,y=10..12"),
+            "line 1, column 4: expected 3 ranges, found 2"
+        );
+        assert_eq!(
+            err("on x=10..12,z=10..12,y=10..12"),
+            "line 1, column 13: expected 'y='"
+        );
+        assert_eq!(
+            err("off x=10..12,y=10..a,z=10..12"),
+            "line 1, column 20: expected i32, found 'a'"
+        );
+        assert_eq!(
+            err("on x=10..12,y=12..10,z=10..12"),
+            "line 1, column 13: range is reversed"
+        );
+    }
+
+    #[test]
+    fn day22_inputs() {
+        crate::answers::check_inputs(&Day22);
+    }
+}
@@ -0,0 +1,355 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+use std::fmt;
+
+use crate::error::{Error, Result};
+use crate::parse;
+use crate::solution::{Answer, Solution};
+
+const ROOMS: usize = 4;
+const HALLWAY: usize = 11;
+const MAX_DEPTH: usize = 4;
+
+/// Hallway cell in front of each room, amphipods cannot stop there.
+const DOORS: [usize; ROOMS] = [2, 4, 6, 8];
+
+const EMPTY: u8 = u8::MAX;
+const ENERGY: [u32; ROOMS] = [1, 10, 100, 1000];
+
+/// Rows inserted in the middle of the rooms when unfolding the diagram.
+const UNFOLDED: [[u8; ROOMS]; 2] = [[3, 2, 1, 0], [3, 1, 0, 2]];
+
+/// Burrow state, amphipods being stored as their room index, from 0 for `A` to 3 for `D`.
+/// Rooms are listed from the hallway down, only their first `depth` cells are used.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Burrow {
+    hallway: [u8; HALLWAY],
+    rooms: [[u8; MAX_DEPTH]; ROOMS],
+    depth: usize,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+struct Node {
+    cost: u32,
+    burrow: Burrow,
+}
+
+impl Ord for Node {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.cost.cmp(&self.cost)
+    }
+}
+
+impl PartialOrd for Node {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Burrow {
+    fn is_organized(&self) -> bool {
+        (0..ROOMS).all(|room| self.room(room).iter().all(|&a| a == room as u8))
+    }
+
+    fn room(&self, room: usize) -> &[u8] {
+        &self.rooms[room][..self.depth]
+    }
+
+    /// Whether the room only holds amphipods of its own type, so they never leave it again.
+    fn is_settled(&self, room: usize) -> bool {
+        self.room(room)
+            .iter()
+            .all(|&a| a == EMPTY || a == room as u8)
+    }
+
+    /// Whether the hallway is empty between `from` and `to`, `from` excluded.
+    fn is_clear(&self, from: usize, to: usize) -> bool {
+        let cells = if from < to {
+            &self.hallway[from + 1..=to]
+        } else {
+            &self.hallway[to..from]
+        };
+
+        cells.iter().all(|&a| a == EMPTY)
+    }
+
+    /// Unfold a 2-deep diagram into the 4-deep one of part 2.
+    fn unfold(&self) -> Result<Self> {
+        if self.depth != 2 {
+            return Err(Error::new(format!(
+                "only a 2-deep burrow can be unfolded, found a {}-deep one",
+                self.depth
+            )));
+        }
+
+        let mut burrow = *self;
+        burrow.depth = 4;
+        for (room, cells) in burrow.rooms.iter_mut().enumerate() {
+            *cells = [cells[0], UNFOLDED[0][room], UNFOLDED[1][room], cells[1]];
+        }
+
+        Ok(burrow)
+    }
+
+    /// States reachable in one move, with the energy the move takes.
+    fn moves(&self) -> Vec<(u32, Burrow)> {
+        let mut moves = vec![];
+
+        // Going home is always the best move, so don't look further when one is possible
+        for from in 0..HALLWAY {
+            let amphipod = self.hallway[from];
+            if amphipod == EMPTY {
+                continue;
+            }
+
+            let room = amphipod as usize;
+            if let Some(next) = self.enter(from, room) {
+                return vec![next];
+            }
+        }
+
+        for (room, &door) in DOORS.iter().enumerate() {
+            if self.is_settled(room) {
+                continue;
+            }
+
+            let slot = match self.room(room).iter().position(|&a| a != EMPTY) {
+                Some(slot) => slot,
+                None => continue,
+            };
+            let amphipod = self.rooms[room][slot];
+
+            for to in 0..HALLWAY {
+                if DOORS.contains(&to) || !self.is_clear(door, to) {
+                    continue;
+                }
+
+                let steps = slot + 1 + door.abs_diff(to);
+                let mut next = *self;
+                next.rooms[room][slot] = EMPTY;
+                next.hallway[to] = amphipod;
+
+                moves.push((steps as u32 * ENERGY[amphipod as usize], next));
+            }
+        }
+
+        moves
+    }
+
+    /// Move the amphipod at `from` in the hallway to the bottom of its room, if it can go.
+    fn enter(&self, from: usize, room: usize) -> Option<(u32, Burrow)> {
+        if !self.is_settled(room) || !self.is_clear(from, DOORS[room]) {
+            return None;
+        }
+
+        let slot = self.room(room).iter().rposition(|&a| a == EMPTY)?;
+        let steps = from.abs_diff(DOORS[room]) + slot + 1;
+
+        let mut next = *self;
+        next.hallway[from] = EMPTY;
+        next.rooms[room][slot] = room as u8;
+
+        Some((steps as u32 * ENERGY[room], next))
+    }
+}
+
+impl fmt::Display for Burrow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let glyph = |a: u8| if a == EMPTY { '.' } else { (b'A' + a) as char };
+
+        writeln!(f, "#############")?;
+        writeln!(
+            f,
+            "#{}#",
+            self.hallway.iter().map(|&a| glyph(a)).collect::<String>()
+        )?;
+        for slot in 0..self.depth {
+            let cells: Vec<String> = (0..ROOMS)
+                .map(|room| glyph(self.rooms[room][slot]).to_string())
+                .collect();
+            let border = if slot == 0 { "##" } else { "  " };
+
+            writeln!(f, "{}#{}#{}", border, cells.join("#"), border.trim())?;
+        }
+        write!(f, "  #########")
+    }
+}
+
+fn parse_input(input: &str) -> Result<Burrow> {
+    let lines: Vec<_> = parse::lines(input).collect();
+
+    let hallway = lines
+        .get(1)
+        .ok_or_else(|| Error::new("expected a hallway on line 2"))?;
+    if hallway.text.get(1..=HALLWAY) != Some(".".repeat(HALLWAY).as_str()) {
+        return Err(hallway.error_at(1, "expected an empty hallway of 11 cells"));
+    }
+
+    let mut burrow = Burrow {
+        hallway: [EMPTY; HALLWAY],
+        rooms: [[EMPTY; MAX_DEPTH]; ROOMS],
+        depth: 0,
+    };
+
+    // Room rows go until the bottom wall
+    for line in &lines[2..] {
+        if line.text.trim() == "#########" {
+            break;
+        }
+        if burrow.depth == MAX_DEPTH {
+            return Err(line.error_at(0, format!("rooms are at most {} deep", MAX_DEPTH)));
+        }
+
+        for (room, door) in DOORS.iter().enumerate() {
+            let column = door + 1;
+            burrow.rooms[room][burrow.depth] = match line.text.as_bytes().get(column) {
+                Some(c @ b'A'..=b'D') => c - b'A',
+                _ => {
+                    let found = line.text.get(column..=column).unwrap_or("");
+                    return Err(line.error_at(
+                        column,
+                        format!("expected an amphipod A to D, found '{}'", found),
+                    ));
+                }
+            };
+        }
+
+        burrow.depth += 1;
+    }
+
+    if burrow.depth == 0 {
+        return Err(Error::new("expected at least one row of rooms"));
+    }
+    for amphipod in 0..ROOMS as u8 {
+        let count = (0..ROOMS)
+            .flat_map(|room| burrow.room(room))
+            .filter(|&&a| a == amphipod)
+            .count();
+
+        if count != burrow.depth {
+            return Err(Error::new(format!(
+                "expected {} amphipods {}, found {}",
+                burrow.depth,
+                (b'A' + amphipod) as char,
+                count
+            )));
+        }
+    }
+
+    Ok(burrow)
+}
+
+/// Least energy needed to organize the amphipods, found with Dijkstra over the burrow states.
+fn solve(start: &Burrow) -> Option<u32> {
+    let mut dist: HashMap<Burrow, u32> = HashMap::new();
+    dist.insert(*start, 0);
+
+    let mut heap: BinaryHeap<Node> = BinaryHeap::new();
+    heap.push(Node {
+        cost: 0,
+        burrow: *start,
+    });
+
+    while let Some(node) = heap.pop() {
+        if node.burrow.is_organized() {
+            return Some(node.cost);
+        }
+
+        if node.cost > dist[&node.burrow] {
+            continue;
+        }
+
+        for (energy, burrow) in node.burrow.moves() {
+            // Update costs if required
+            let cost = node.cost + energy;
+            if cost < *dist.get(&burrow).unwrap_or(&u32::MAX) {
+                heap.push(Node { cost, burrow });
+
+                dist.insert(burrow, cost);
+            }
+        }
+    }
+
+    None
+}
+
+fn organize(burrow: &Burrow) -> Result<u32> {
+    solve(burrow).ok_or_else(|| Error::new("the amphipods cannot be organized"))
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+    const TITLE: &'static str = "Amphipod";
+
+    type Input = Burrow;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(burrow: &Self::Input) -> Result<Answer> {
+        Ok(organize(burrow)?.into())
+    }
+
+    fn part2(burrow: &Self::Input) -> Result<Option<Answer>> {
+        Ok(Some(organize(&burrow.unfold()?)?.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "#############
+#...........#
+###B#C#B#D###
+  #A#D#C#A#
+  #########";
+
+    #[test]
+    fn day23_part1() {
+        assert_eq!(solve(&parse_input(INPUT).unwrap()), Some(12521));
+    }
+
+    #[test]
+    fn day23_part2() {
+        let burrow = parse_input(INPUT).unwrap().unfold().unwrap();
+
+        assert_eq!(
+            burrow.to_string(),
+            "#############
+#...........#
+###B#C#B#D###
+  #D#C#B#A#
+  #D#B#A#C#
+  #A#D#C#A#
+  #########"
+        );
+        assert_eq!(solve(&burrow), Some(44169));
+    }
+
+    #[test]
+    fn day23_errors() {
+        let err = |input| parse_input(input).unwrap_err().to_string();
+
+        assert_eq!(
+            err("#############\n#.....A.....#\n###B#C#B#D###"),
+            "line 2, column 2: expected an empty hallway of 11 cells"
+        );
+        assert_eq!(
+            err("#############\n#...........#\n###B#C#E#D###"),
+            "line 3, column 8: expected an amphipod A to D, found 'E'"
+        );
+        assert_eq!(
+            err("#############\n#...........#\n###B#C#B#D###\n  #A#D#C#B#"),
+            "expected 2 amphipods A, found 1"
+        );
+    }
+
+    #[test]
+    fn day23_inputs() {
+        crate::answers::check_inputs(&Day23);
+    }
+}
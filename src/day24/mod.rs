@@ -0,0 +1,613 @@
+use std::fmt;
+
+use crate::error::{Error, Result};
+use crate::parse::{self, Line};
+use crate::solution::{Answer, Solution, Tool};
+
+const REGISTERS: [char; 4] = ['w', 'x', 'y', 'z'];
+const DIGITS: usize = 14;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Operand {
+    Register(usize),
+    Value(i64),
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Instruction {
+    Inp(usize),
+    Add(usize, Operand),
+    Mul(usize, Operand),
+    Div(usize, Operand),
+    Mod(usize, Operand),
+    Eql(usize, Operand),
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operand::Register(r) => write!(f, "{}", REGISTERS[*r]),
+            Operand::Value(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (name, a, b) = match *self {
+            Instruction::Inp(a) => return write!(f, "inp {}", REGISTERS[a]),
+            Instruction::Add(a, b) => ("add", a, b),
+            Instruction::Mul(a, b) => ("mul", a, b),
+            Instruction::Div(a, b) => ("div", a, b),
+            Instruction::Mod(a, b) => ("mod", a, b),
+            Instruction::Eql(a, b) => ("eql", a, b),
+        };
+
+        write!(f, "{} {} {}", name, REGISTERS[a], b)
+    }
+}
+
+fn parse_register(line: &Line, part: &str) -> Result<usize> {
+    let mut chars = part.chars();
+
+    match (chars.next(), chars.next()) {
+        (Some(c), None) if REGISTERS.contains(&c) => {
+            Ok(REGISTERS.iter().position(|&r| r == c).unwrap())
+        }
+        _ => Err(line.error(
+            part,
+            format!("expected a register w, x, y or z, found '{}'", part),
+        )),
+    }
+}
+
+fn parse_operand<'a>(line: &Line<'a>, part: &'a str) -> Result<Operand> {
+    if part.chars().all(|c| c.is_ascii_alphabetic()) {
+        parse_register(line, part).map(Operand::Register)
+    } else {
+        line.parse(part).map(Operand::Value)
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Instruction>> {
+    parse::lines(input)
+        .map(|l| {
+            let mut parts = l.text.split(' ');
+            let name = parts.next().unwrap_or("");
+            let a = parts
+                .next()
+                .ok_or_else(|| l.error_at(l.text.len(), "expected a register"))?;
+            let a = parse_register(&l, a)?;
+
+            if name == "inp" {
+                return match parts.next() {
+                    None => Ok(Instruction::Inp(a)),
+                    Some(extra) => Err(l.error(extra, "unexpected operand")),
+                };
+            }
+
+            let b = parts
+                .next()
+                .ok_or_else(|| l.error_at(l.text.len(), "expected an operand"))?;
+            let b = parse_operand(&l, b)?;
+            if let Some(extra) = parts.next() {
+                return Err(l.error(extra, "unexpected operand"));
+            }
+
+            match name {
+                "add" => Ok(Instruction::Add(a, b)),
+                "mul" => Ok(Instruction::Mul(a, b)),
+                "div" => Ok(Instruction::Div(a, b)),
+                "mod" => Ok(Instruction::Mod(a, b)),
+                "eql" => Ok(Instruction::Eql(a, b)),
+                _ => Err(l.error(
+                    name,
+                    format!(
+                        "expected one of inp, add, mul, div, mod or eql, found '{}'",
+                        name
+                    ),
+                )),
+            }
+        })
+        .collect()
+}
+
+/// Arithmetic logic unit, holding the `w`, `x`, `y` and `z` registers.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Alu {
+    pub registers: [i64; 4],
+}
+
+fn overflow(instruction: Instruction) -> Error {
+    Error::new(format!("'{}' overflows", instruction))
+}
+
+impl Alu {
+    fn value(&self, operand: Operand) -> i64 {
+        match operand {
+            Operand::Register(r) => self.registers[r],
+            Operand::Value(v) => v,
+        }
+    }
+
+    /// Execute one instruction, reading from `inputs` for `inp`.
+    pub fn step(
+        &mut self,
+        instruction: Instruction,
+        inputs: &mut impl Iterator<Item = i64>,
+    ) -> Result<()> {
+        match instruction {
+            Instruction::Inp(a) => {
+                self.registers[a] = inputs
+                    .next()
+                    .ok_or_else(|| Error::new("no input left for 'inp'"))?;
+            }
+            Instruction::Add(a, b) => {
+                self.registers[a] = self.registers[a]
+                    .checked_add(self.value(b))
+                    .ok_or_else(|| overflow(instruction))?
+            }
+            Instruction::Mul(a, b) => {
+                self.registers[a] = self.registers[a]
+                    .checked_mul(self.value(b))
+                    .ok_or_else(|| overflow(instruction))?
+            }
+            Instruction::Div(a, b) => {
+                let b = self.value(b);
+                if b == 0 {
+                    return Err(Error::new(format!("'{}' divides by zero", instruction)));
+                }
+                self.registers[a] = self.registers[a]
+                    .checked_div(b)
+                    .ok_or_else(|| overflow(instruction))?;
+            }
+            Instruction::Mod(a, b) => {
+                let b = self.value(b);
+                if self.registers[a] < 0 || b <= 0 {
+                    return Err(Error::new(format!(
+                        "'{}' computes {} mod {}",
+                        instruction, self.registers[a], b
+                    )));
+                }
+                self.registers[a] %= b;
+            }
+            Instruction::Eql(a, b) => {
+                self.registers[a] = (self.registers[a] == self.value(b)) as i64
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Run a whole program on `inputs` from zeroed registers.
+    pub fn run(program: &[Instruction], inputs: &[i64]) -> Result<Self> {
+        let mut alu = Self::default();
+        let mut inputs = inputs.iter().copied();
+
+        for (idx, &instruction) in program.iter().enumerate() {
+            alu.step(instruction, &mut inputs)
+                .map_err(|e| Error::new(format!("instruction {}: {}", idx + 1, e.message())))?;
+        }
+
+        Ok(alu)
+    }
+}
+
+impl fmt::Display for Alu {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [w, x, y, z] = self.registers;
+
+        write!(f, "w={} x={} y={} z={}", w, x, y, z)
+    }
+}
+
+/// One of the 14 blocks of MONAD, checking one digit.
+///
+/// `z` is used as a stack of base 26 numbers. A pushing block (`div z 1`) pushes its digit
+/// plus `offset`. A popping block (`div z 26`) pops the top and pushes again unless its digit
+/// is the popped value plus `check`: the model number is valid when every pop matches.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct Block {
+    pop: bool,
+    check: i64,
+    offset: i64,
+}
+
+/// Instructions of a block, `_` standing for the `div z`, `add x` and `add y` parameters.
+const BLOCK: [&str; 18] = [
+    "inp w", "mul x 0", "add x z", "mod x 26", "div z _", "add x _", "eql x w", "eql x 0",
+    "mul y 0", "add y 25", "mul y x", "add y 1", "mul z y", "mul y 0", "add y w", "add y _",
+    "mul y x", "add z y",
+];
+
+/// Equality `digits[right] == digits[left] + diff` that a valid model number satisfies.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct Constraint {
+    left: usize,
+    right: usize,
+    diff: i64,
+}
+
+fn parse_blocks(program: &[Instruction]) -> Result<Vec<Block>> {
+    if program.len() != DIGITS * BLOCK.len() {
+        return Err(Error::new(format!(
+            "expected {} blocks of {} instructions, found {} instructions",
+            DIGITS,
+            BLOCK.len(),
+            program.len()
+        )));
+    }
+
+    program
+        .chunks(BLOCK.len())
+        .enumerate()
+        .map(|(idx, chunk)| {
+            let mut params = vec![];
+
+            for (offset, (instruction, expected)) in chunk.iter().zip(BLOCK).enumerate() {
+                let text = instruction.to_string();
+
+                let matched = if let Some(prefix) = expected.strip_suffix('_') {
+                    text.strip_prefix(prefix)
+                        .and_then(|p| p.parse().ok())
+                        .map(|param| params.push(param))
+                        .is_some()
+                } else {
+                    text == expected
+                };
+
+                if !matched {
+                    return Err(Error::new(format!(
+                        "instruction {}: expected '{}', found '{}'",
+                        idx * BLOCK.len() + offset + 1,
+                        expected,
+                        text
+                    )));
+                }
+            }
+
+            let pop = match params[0] {
+                1 => false,
+                26 => true,
+                div => {
+                    return Err(Error::new(format!(
+                        "block {}: expected 'div z 1' or 'div z 26', found 'div z {}'",
+                        idx + 1,
+                        div
+                    )))
+                }
+            };
+
+            Ok(Block {
+                pop,
+                check: params[1],
+                offset: params[2],
+            })
+        })
+        .collect()
+}
+
+/// Pair each popping block with the pushing one it pops.
+fn constraints(blocks: &[Block]) -> Result<Vec<Constraint>> {
+    let mut stack = vec![];
+    let mut constraints = vec![];
+
+    for (right, block) in blocks.iter().enumerate() {
+        if !block.pop {
+            // A digit never matches a check above 9, so the block always pushes
+            if block.check <= 9 {
+                return Err(Error::new(format!(
+                    "block {}: expected a check above 9 for a pushing block, found {}",
+                    right + 1,
+                    block.check
+                )));
+            }
+
+            stack.push(right);
+            continue;
+        }
+
+        let left = stack
+            .pop()
+            .ok_or_else(|| Error::new(format!("block {} pops an empty stack", right + 1)))?;
+
+        constraints.push(Constraint {
+            left,
+            right,
+            diff: blocks[left].offset + block.check,
+        });
+    }
+
+    if !stack.is_empty() {
+        return Err(Error::new(format!(
+            "{} digit(s) are pushed but never popped",
+            stack.len()
+        )));
+    }
+
+    Ok(constraints)
+}
+
+/// Largest or smallest model number accepted by MONAD, checked by running it on the ALU.
+fn solve(program: &[Instruction], largest: bool) -> Result<u64> {
+    let constraints = constraints(&parse_blocks(program)?)?;
+
+    let mut digits = [0; DIGITS];
+    for Constraint { left, right, diff } in constraints {
+        // Both digits are between 1 and 9
+        let range = (1.max(1 - diff))..=(9.min(9 - diff));
+        if range.is_empty() {
+            return Err(Error::new(format!(
+                "digits {} and {} cannot differ by {}",
+                left + 1,
+                right + 1,
+                diff
+            )));
+        }
+
+        digits[left] = if largest {
+            *range.end()
+        } else {
+            *range.start()
+        };
+        digits[right] = digits[left] + diff;
+    }
+
+    let alu = Alu::run(program, &digits)?;
+    if alu.registers[3] != 0 {
+        return Err(Error::new(format!(
+            "MONAD rejects the model number found by the analysis, {}",
+            alu
+        )));
+    }
+
+    Ok(digits.iter().fold(0, |n, &d| n * 10 + d as u64))
+}
+
+// Format `+ v` or `- v`, to add `v` in an expression
+fn signed(v: i64) -> String {
+    if v < 0 {
+        format!("- {}", -v)
+    } else {
+        format!("+ {}", v)
+    }
+}
+
+/// Listing of the program, block by block, with what each block does to the stack.
+fn disassemble(program: &[Instruction]) -> String {
+    let mut out = String::new();
+
+    let analysis = parse_blocks(program).and_then(|blocks| Ok((constraints(&blocks)?, blocks)));
+    let blocks = match &analysis {
+        Ok((_, blocks)) => Some(blocks),
+        Err(e) => {
+            out += &format!("; not a MONAD program: {}\n", e);
+            None
+        }
+    };
+
+    for (idx, instruction) in program.iter().enumerate() {
+        if let (Some(blocks), 0) = (blocks, idx % BLOCK.len()) {
+            let digit = idx / BLOCK.len();
+            let block = &blocks[digit];
+
+            if block.pop {
+                out += &format!(
+                    "; digit {}: pop, push d{} {} unless d{} == top {}\n",
+                    digit + 1,
+                    digit + 1,
+                    signed(block.offset),
+                    digit + 1,
+                    signed(block.check)
+                );
+            } else {
+                out += &format!(
+                    "; digit {}: push d{} {}\n",
+                    digit + 1,
+                    digit + 1,
+                    signed(block.offset)
+                );
+            }
+        }
+
+        out += &format!("{:4}  {}\n", idx + 1, instruction);
+    }
+
+    if let Ok((constraints, _)) = &analysis {
+        out += "; valid when\n";
+        for c in constraints {
+            out += &format!("; d{} == d{} {}\n", c.right + 1, c.left + 1, signed(c.diff));
+        }
+    }
+
+    out
+}
+
+/// Registers after each instruction, running the program on the digits of `number`.
+fn trace(program: &[Instruction], number: &str) -> Result<String> {
+    let inputs = number
+        .chars()
+        .map(|c| c.to_digit(10).map(i64::from))
+        .collect::<Option<Vec<_>>>()
+        .ok_or_else(|| Error::new(format!("expected digits, found '{}'", number)))?;
+
+    let mut out = String::new();
+    let mut alu = Alu::default();
+    let mut it = inputs.iter().copied();
+
+    for (idx, &instruction) in program.iter().enumerate() {
+        alu.step(instruction, &mut it)
+            .map_err(|e| Error::new(format!("instruction {}: {}", idx + 1, e.message())))?;
+        out += &format!("{:4}  {:<10}  {}\n", idx + 1, instruction.to_string(), alu);
+    }
+
+    let valid = if alu.registers[3] == 0 {
+        "valid"
+    } else {
+        "invalid"
+    };
+    out += &format!("{} is {}", number, valid);
+
+    Ok(out)
+}
+
+fn disassemble_tool(input: &str, args: &[String]) -> Result<String> {
+    if let Some(arg) = args.first() {
+        return Err(Error::new(format!("unexpected argument '{}'", arg)));
+    }
+
+    Ok(disassemble(&parse_input(input)?))
+}
+
+fn trace_tool(input: &str, args: &[String]) -> Result<String> {
+    match args {
+        [number] => trace(&parse_input(input)?, number),
+        _ => Err(Error::new("expected a model number")),
+    }
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+    const TITLE: &'static str = "Arithmetic Logic Unit";
+
+    type Input = Vec<Instruction>;
+
+    const TOOLS: &'static [Tool] = &[
+        Tool {
+            name: "disasm",
+            usage: "list the program block by block, with the digit constraints of MONAD",
+            run: disassemble_tool,
+        },
+        Tool {
+            name: "trace",
+            usage: "<NUMBER>  print the registers after each instruction run on NUMBER",
+            run: trace_tool,
+        },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(program: &Self::Input) -> Result<Answer> {
+        Ok(solve(program, true)?.into())
+    }
+
+    fn part2(program: &Self::Input) -> Result<Option<Answer>> {
+        Ok(Some(solve(program, false)?.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::load_named;
+
+    const BINARY: &str = "inp w
+add z w
+mod z 2
+div w 2
+add y w
+mod y 2
+div w 2
+add x w
+mod x 2
+div w 2
+mod w 2";
+
+    #[test]
+    fn day24_alu() {
+        let program = parse_input(BINARY).unwrap();
+
+        assert_eq!(Alu::run(&program, &[13]).unwrap().registers, [1, 1, 0, 1]);
+        assert_eq!(program[1].to_string(), "add z w");
+
+        let negate = parse_input("inp x\nmul x -1").unwrap();
+        assert_eq!(Alu::run(&negate, &[7]).unwrap().registers[1], -7);
+        assert_eq!(
+            Alu::run(&negate, &[]).unwrap_err().to_string(),
+            "instruction 1: no input left for 'inp'"
+        );
+        assert_eq!(
+            Alu::run(&parse_input("div x 0").unwrap(), &[])
+                .unwrap_err()
+                .to_string(),
+            "instruction 1: 'div x 0' divides by zero"
+        );
+
+        let program = "inp x\nmul x 1000000000\nmul x 1000000000\nmul x 1000000000";
+        assert_eq!(
+            Alu::run(&parse_input(program).unwrap(), &[9])
+                .unwrap_err()
+                .to_string(),
+            "instruction 4: 'mul x 1000000000' overflows"
+        );
+
+        let program = "inp x\ninp y\ndiv x y";
+        assert_eq!(
+            Alu::run(&parse_input(program).unwrap(), &[i64::MIN, -1])
+                .unwrap_err()
+                .to_string(),
+            "instruction 3: 'div x y' overflows"
+        );
+    }
+
+    #[test]
+    fn day24_solve() {
+        let program = parse_input(&load_named(24, "generated").unwrap()).unwrap();
+
+        let largest = solve(&program, true).unwrap();
+        let smallest = solve(&program, false).unwrap();
+        assert!(smallest <= largest);
+
+        // Nudging the last digit breaks its constraint
+        let digits = |n: u64| -> Vec<i64> {
+            n.to_string()
+                .chars()
+                .map(|c| c.to_digit(10).unwrap() as i64)
+                .collect()
+        };
+        assert_eq!(
+            Alu::run(&program, &digits(largest)).unwrap().registers[3],
+            0
+        );
+        assert_ne!(
+            Alu::run(&program, &digits(largest - 1)).unwrap().registers[3],
+            0
+        );
+
+        assert!(disassemble(&program).contains("; valid when"));
+        assert!(trace(&program, &largest.to_string())
+            .unwrap()
+            .ends_with("is valid"));
+        assert_eq!(
+            solve(&parse_input(BINARY).unwrap(), true)
+                .unwrap_err()
+                .to_string(),
+            "expected 14 blocks of 18 instructions, found 11 instructions"
+        );
+    }
+
+    #[test]
+    fn day24_errors() {
+        let err = |input| parse_input(input).unwrap_err().to_string();
+
+        assert_eq!(
+            err("inp w\nsub x 1"),
+            "line 2, column 1: expected one of inp, add, mul, div, mod or eql, found 'sub'"
+        );
+        assert_eq!(
+            err("add q 1"),
+            "line 1, column 5: expected a register w, x, y or z, found 'q'"
+        );
+        assert_eq!(err("mul x"), "line 1, column 6: expected an operand");
+        assert_eq!(err("inp w 1"), "line 1, column 7: unexpected operand");
+    }
+
+    #[test]
+    fn day24_inputs() {
+        crate::answers::check_inputs(&Day24);
+    }
+}
@@ -0,0 +1,788 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::{Error, Result};
+use crate::grid::Grid;
+use crate::pixmap::Pixmap;
+use crate::solution::{Answer, Solution, Tool};
+use crate::tool_args::{unexpected, FrameFormat, Frames, Options};
+
+const EMPTY_COLOR: [u8; 3] = [8, 24, 64];
+const HERD_COLORS: [[u8; 3]; 4] = [
+    [255, 160, 32],
+    [64, 224, 160],
+    [224, 64, 96],
+    [96, 128, 255],
+];
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    fn offset(&self) -> (isize, isize) {
+        match self {
+            Direction::North => (0, -1),
+            Direction::East => (1, 0),
+            Direction::South => (0, 1),
+            Direction::West => (-1, 0),
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "north" => Ok(Direction::North),
+            "east" => Ok(Direction::East),
+            "south" => Ok(Direction::South),
+            "west" => Ok(Direction::West),
+            _ => Err(Error::new(format!(
+                "expected 'north', 'east', 'south' or 'west', found '{}'",
+                s
+            ))),
+        }
+    }
+}
+
+/// Sea cucumbers drawn with the same glyph, all facing the same way.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct HerdRule {
+    pub glyph: char,
+    pub direction: Direction,
+}
+
+/// How the sea cucumbers move: each step, every herd moves in turn, in the order of `herds`.
+/// When `wrap` is set, cucumbers leaving the map come back on the opposite side, otherwise they
+/// stop at the border.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Rules {
+    pub herds: Vec<HerdRule>,
+    pub wrap: bool,
+}
+
+impl Default for Rules {
+    /// The puzzle rules: the east facing herd moves first, then the south facing one.
+    fn default() -> Self {
+        Self {
+            herds: vec![
+                HerdRule {
+                    glyph: '>',
+                    direction: Direction::East,
+                },
+                HerdRule {
+                    glyph: 'v',
+                    direction: Direction::South,
+                },
+            ],
+            wrap: true,
+        }
+    }
+}
+
+impl FromStr for Rules {
+    type Err = Error;
+
+    /// Parse herds given as `<GLYPH>:<DIRECTION>`, comma separated, like `>:east,v:south`.
+    /// The borders wrap.
+    fn from_str(s: &str) -> Result<Self> {
+        let mut herds: Vec<HerdRule> = vec![];
+
+        for herd in s.split(',') {
+            let (glyph, direction) = herd.split_once(':').ok_or_else(|| {
+                Error::new(format!("expected '<glyph>:<direction>', found '{}'", herd))
+            })?;
+
+            let mut chars = glyph.chars();
+            let glyph = match (chars.next(), chars.next()) {
+                (Some(c), None) if c != '.' && !c.is_whitespace() => c,
+                _ => return Err(Error::new(format!("invalid glyph '{}'", glyph))),
+            };
+            if herds.iter().any(|h| h.glyph == glyph) {
+                return Err(Error::new(format!("glyph '{}' is used twice", glyph)));
+            }
+
+            herds.push(HerdRule {
+                glyph,
+                direction: direction.parse()?,
+            });
+        }
+
+        Ok(Self { herds, wrap: true })
+    }
+}
+
+/// Content of a cell: empty, or a sea cucumber of the herd at this index in the rules.
+pub type Cell = Option<usize>;
+
+fn parse_map(input: &str, rules: &Rules) -> Result<Grid<Cell>> {
+    let glyphs: Vec<String> = std::iter::once('.')
+        .chain(rules.herds.iter().map(|h| h.glyph))
+        .map(|c| format!("'{}'", c))
+        .collect();
+    let expected = match glyphs.split_last() {
+        Some((last, [])) => last.clone(),
+        Some((last, rest)) => format!("{} or {}", rest.join(", "), last),
+        None => unreachable!("'.' is always there"),
+    };
+
+    let grid = Grid::parse(input, &expected, |c| match c {
+        '.' => Some(None),
+        _ => rules.herds.iter().position(|h| h.glyph == c).map(Some),
+    })?;
+
+    Ok(grid.wrapping(rules.wrap))
+}
+
+fn parse_input(input: &str) -> Result<Grid<Cell>> {
+    parse_map(input, &Rules::default())
+}
+
+/// Move the herds cell by cell, the reference [`Herd`] is checked against.
+#[cfg(test)]
+fn run(mut input: Grid<Cell>, rules: &Rules) -> (Grid<Cell>, bool) {
+    let mut updated = false;
+
+    for (herd, rule) in rules.herds.iter().enumerate() {
+        let mut next_input = input.clone();
+
+        for (pos, &cell) in input.iter() {
+            if cell != Some(herd) {
+                continue;
+            }
+
+            // Cucumbers facing the border of a non wrapping map stay there
+            let next_pos = match input.offset(pos, rule.direction.offset()) {
+                Some(next_pos) => next_pos,
+                None => continue,
+            };
+
+            if input[next_pos].is_none() {
+                next_input[pos] = None;
+                next_input[next_pos] = Some(herd);
+
+                updated = true;
+            }
+        }
+
+        input = next_input;
+    }
+
+    (input, updated)
+}
+
+/// Herds stored as one bitmask per row and per herd, bit `x` of a row standing for column `x`.
+///
+/// A herd moves a whole row at a time. Moving east or west, the cucumbers whose next cell is
+/// free are the herd masked by the rotated free cells, and they move by rotating them back.
+/// Moving north or south, rows are masked with the free cells of the next row instead.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Herd {
+    width: usize,
+    height: usize,
+    /// Number of words of a row.
+    words: usize,
+    rules: Rules,
+    /// Bitmasks of the rows of each herd, one after the other.
+    layers: Vec<Vec<u64>>,
+}
+
+// Set `dst` so that its bit `x` is bit `x + 1` of `src`, wrapping around a row of `width` bits
+fn rotate_next(src: &[u64], dst: &mut [u64], width: usize) {
+    for i in 0..src.len() {
+        dst[i] = (src[i] >> 1) | src.get(i + 1).map_or(0, |w| w << 63);
+    }
+
+    let last = width - 1;
+    dst[last / 64] |= (src[0] & 1) << (last % 64);
+}
+
+// Set `dst` so that its bit `x` is bit `x - 1` of `src`, wrapping around a row of `width` bits
+fn rotate_prev(src: &[u64], dst: &mut [u64], width: usize) {
+    for i in 0..src.len() {
+        dst[i] = (src[i] << 1) | if i > 0 { src[i - 1] >> 63 } else { 0 };
+    }
+
+    // Drop the bit shifted out of the row, and bring it back at the start
+    let last = width - 1;
+    if width % 64 != 0 {
+        dst[width / 64] &= !(1 << (width % 64));
+    }
+    dst[0] |= (src[last / 64] >> (last % 64)) & 1;
+}
+
+impl Herd {
+    pub fn from_grid(grid: &Grid<Cell>, rules: &Rules) -> Self {
+        let words = grid.width().div_ceil(64);
+        let mut herd = Self {
+            width: grid.width(),
+            height: grid.height(),
+            words,
+            rules: rules.clone(),
+            layers: vec![vec![0; words * grid.height()]; rules.herds.len()],
+        };
+
+        for ((x, y), cell) in grid.iter() {
+            if let Some(idx) = cell {
+                herd.layers[*idx][y * words + x / 64] |= 1 << (x % 64);
+            }
+        }
+
+        herd
+    }
+
+    pub fn cell(&self, (x, y): (usize, usize)) -> Cell {
+        let (word, bit) = (y * self.words + x / 64, x % 64);
+
+        self.layers
+            .iter()
+            .position(|layer| layer[word] >> bit & 1 == 1)
+    }
+
+    /// Hash of the state, quicker than the `Hash` implementation on large maps.
+    pub fn fingerprint(&self) -> u64 {
+        self.layers.iter().flatten().fold(0, |h: u64, &w| {
+            (h.rotate_left(5) ^ w).wrapping_mul(0x517c_c1b7_2722_0a95)
+        })
+    }
+
+    pub fn to_grid(&self) -> Grid<Cell> {
+        Grid::from_fn(self.width, self.height, |pos| self.cell(pos)).wrapping(self.rules.wrap)
+    }
+
+    /// Move every herd in turn. Return whether any cucumber moved.
+    pub fn step(&mut self) -> bool {
+        let mut moved = false;
+        for herd in 0..self.layers.len() {
+            moved |= self.move_herd(herd);
+        }
+
+        moved
+    }
+
+    // Row reached by moving `dy` rows from `y`, if any
+    fn row(&self, y: usize, dy: isize) -> Option<usize> {
+        let y = y as isize + dy;
+
+        if self.rules.wrap {
+            Some(y.rem_euclid(self.height as isize) as usize)
+        } else {
+            (0..self.height as isize).contains(&y).then_some(y as usize)
+        }
+    }
+
+    fn move_herd(&mut self, herd: usize) -> bool {
+        let words = self.words;
+        let mut occupied = vec![0; self.height * words];
+        for layer in &self.layers {
+            for (o, w) in occupied.iter_mut().zip(layer) {
+                *o |= w;
+            }
+        }
+
+        let mut movers = vec![0; self.height * words];
+        let direction = self.rules.herds[herd].direction;
+
+        match direction {
+            Direction::East | Direction::West => {
+                let east = direction == Direction::East;
+                let (width, wrap) = (self.width, self.rules.wrap);
+                let mut ahead = vec![0; words];
+                let mut arrived = vec![0; words];
+                let layer = &mut self.layers[herd];
+
+                for y in 0..self.height {
+                    let row = y * words..(y + 1) * words;
+
+                    // Bit `x` of `ahead` tells whether the cell in front of `x` is taken, the
+                    // border being a wall when the map doesn't wrap
+                    if east {
+                        rotate_next(&occupied[row.clone()], &mut ahead, width);
+                        if !wrap {
+                            ahead[(width - 1) / 64] |= 1 << ((width - 1) % 64);
+                        }
+                    } else {
+                        rotate_prev(&occupied[row.clone()], &mut ahead, width);
+                        if !wrap {
+                            ahead[0] |= 1;
+                        }
+                    }
+
+                    let movers = &mut movers[row.clone()];
+                    for i in 0..words {
+                        movers[i] = layer[row.start + i] & !ahead[i];
+                    }
+                    if east {
+                        rotate_prev(movers, &mut arrived, width);
+                    } else {
+                        rotate_next(movers, &mut arrived, width);
+                    }
+
+                    for i in 0..words {
+                        let cells = &mut layer[row.start + i];
+                        *cells = (*cells & !movers[i]) | arrived[i];
+                    }
+                }
+            }
+            Direction::North | Direction::South => {
+                let dy = direction.offset().1;
+
+                for y in 0..self.height {
+                    if let Some(ahead) = self.row(y, dy) {
+                        for i in 0..words {
+                            movers[y * words + i] =
+                                self.layers[herd][y * words + i] & !occupied[ahead * words + i];
+                        }
+                    }
+                }
+
+                for y in 0..self.height {
+                    let behind = self.row(y, -dy);
+                    let layer = &mut self.layers[herd];
+
+                    for i in 0..words {
+                        let idx = y * words + i;
+                        let arrived = behind.map_or(0, |b| movers[b * words + i]);
+                        layer[idx] = (layer[idx] & !movers[idx]) | arrived;
+                    }
+                }
+            }
+        }
+
+        movers.iter().any(|&m| m != 0)
+    }
+}
+
+impl fmt::Display for Herd {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in 0..self.height {
+            if y > 0 {
+                writeln!(f)?;
+            }
+
+            for x in 0..self.width {
+                let glyph = self.cell((x, y)).map_or('.', |h| self.rules.herds[h].glyph);
+                write!(f, "{}", glyph)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// How a simulation ends.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum End {
+    /// Nothing moves during `step`, the first step being 1.
+    Stopped { step: usize },
+    /// The state after `start + length` steps is the same as after `start` steps, and the
+    /// herds loop forever.
+    Cycle { start: usize, length: usize },
+}
+
+/// Iterator over the states of the herds, from the initial one, until they stop moving or
+/// come back to an earlier state.
+///
+/// Earlier states are remembered by their hash only, which keeps memory low on large maps. When a
+/// hash comes back, the earlier states are replayed from the initial one to rule out collisions.
+pub struct Simulation {
+    herd: Herd,
+    initial: Herd,
+    step: usize,
+    seen: HashMap<u64, Vec<usize>>,
+    end: Option<End>,
+    started: bool,
+}
+
+impl Simulation {
+    pub fn new(grid: &Grid<Cell>, rules: &Rules) -> Self {
+        let mut simulation = Self {
+            herd: Herd::from_grid(grid, rules),
+            initial: Herd::from_grid(grid, rules),
+            step: 0,
+            seen: HashMap::new(),
+            end: None,
+            started: false,
+        };
+        simulation.remember();
+
+        simulation
+    }
+
+    /// How the simulation ended, once the iterator is exhausted.
+    pub fn end(&self) -> Option<End> {
+        self.end
+    }
+
+    /// Do one step, without going through the iterator and copying the state. Return whether
+    /// the simulation goes on.
+    pub fn advance(&mut self) -> bool {
+        if self.end.is_some() {
+            return false;
+        }
+
+        if !self.herd.step() {
+            self.end = Some(End::Stopped {
+                step: self.step + 1,
+            });
+            return false;
+        }
+
+        self.step += 1;
+
+        if let Some(start) = self.remember() {
+            self.end = Some(End::Cycle {
+                start,
+                length: self.step - start,
+            });
+            return false;
+        }
+
+        true
+    }
+
+    /// Remember the current state, returning the step it was already reached at, if any.
+    fn remember(&mut self) -> Option<usize> {
+        let steps = self.seen.entry(self.herd.fingerprint()).or_default();
+
+        // Steps are in increasing order, so a single replay checks them all
+        if !steps.is_empty() {
+            let mut herd = self.initial.clone();
+            let mut step = 0;
+            for &candidate in steps.iter() {
+                while step < candidate {
+                    herd.step();
+                    step += 1;
+                }
+                if herd == self.herd {
+                    return Some(candidate);
+                }
+            }
+        }
+
+        steps.push(self.step);
+        None
+    }
+}
+
+impl Iterator for Simulation {
+    /// Number of steps done and the state after them.
+    type Item = (usize, Herd);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.started {
+            self.started = true;
+            return Some((0, self.herd.clone()));
+        }
+
+        self.advance().then(|| (self.step, self.herd.clone()))
+    }
+}
+
+fn part1(cells: &Grid<Cell>) -> Result<usize> {
+    let mut simulation = Simulation::new(cells, &Rules::default());
+    while simulation.advance() {}
+
+    match simulation.end() {
+        Some(End::Stopped { step }) => Ok(step),
+        Some(End::Cycle { start, length }) => Err(Error::new(format!(
+            "the herds never stop, looping every {} steps from step {}",
+            length, start
+        ))),
+        None => unreachable!("the simulation runs until it ends"),
+    }
+}
+
+// Handle the `--herds` and `--no-wrap` options of the tools, returning whether `arg` is one
+fn rules_option(arg: &str, options: &mut Options, rules: &mut Rules) -> Result<bool> {
+    match arg {
+        "--herds" => {
+            *rules = Rules {
+                wrap: rules.wrap,
+                ..options.value(arg)?.parse()?
+            };
+        }
+        "--no-wrap" => rules.wrap = false,
+        _ => return Ok(false),
+    }
+
+    Ok(true)
+}
+
+fn describe_end(end: Option<End>) -> String {
+    match end {
+        Some(End::Stopped { step }) => format!("nothing moves at step {}", step),
+        Some(End::Cycle { start, length }) => {
+            format!("the herds loop every {} steps from step {}", length, start)
+        }
+        None => unreachable!("the simulation runs until it ends"),
+    }
+}
+
+fn simulate_tool(input: &str, args: &[String]) -> Result<String> {
+    let mut rules = Rules::default();
+
+    let mut options = Options::new(args);
+    while let Some(arg) = options.arg() {
+        if !rules_option(arg, &mut options, &mut rules)? {
+            return Err(unexpected(arg));
+        }
+    }
+
+    let mut simulation = Simulation::new(&parse_map(input, &rules)?, &rules);
+    while simulation.advance() {}
+
+    Ok(describe_end(simulation.end()))
+}
+
+fn frames_tool(input: &str, args: &[String]) -> Result<String> {
+    let mut frames = Frames::new("step", &[FrameFormat::Ppm, FrameFormat::Png]);
+    let mut rules = Rules::default();
+
+    let mut options = Options::new(args);
+    while let Some(arg) = options.arg() {
+        if !rules_option(arg, &mut options, &mut rules)? && !frames.option(arg, &mut options)? {
+            return Err(unexpected(arg));
+        }
+    }
+    frames.start()?;
+
+    let every = frames.every;
+    let mut simulation = Simulation::new(&parse_map(input, &rules)?, &rules);
+    for (step, herd) in simulation.by_ref().filter(|(step, _)| step % every == 0) {
+        frames.write(step, &herd, |format| {
+            let pixmap = Pixmap::from_fn(herd.width, herd.height, |x, y| match herd.cell((x, y)) {
+                Some(h) => HERD_COLORS[h % HERD_COLORS.len()],
+                None => EMPTY_COLOR,
+            });
+
+            Ok(match format {
+                FrameFormat::Png => pixmap.to_png(),
+                _ => pixmap.to_ppm(),
+            })
+        })?;
+    }
+
+    Ok(frames.finish() + &describe_end(simulation.end()))
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+    const TITLE: &'static str = "Sea Cucumber";
+
+    type Input = Grid<Cell>;
+
+    const TOOLS: &'static [Tool] = &[
+        Tool {
+            name: "frames",
+            usage: "[--every <N>] [--format <text|ppm|png>] [--out <DIR>] [RULES]  print every \
+                    Nth state, or write it to DIR as one file per frame",
+            run: frames_tool,
+        },
+        Tool {
+            name: "simulate",
+            usage: "[RULES]  run until the herds stop or loop. RULES are '--herds \
+                    <GLYPH>:<DIRECTION>,...', the herds moving in this order, and '--no-wrap' \
+                    to stop the cucumbers at the borders",
+            run: simulate_tool,
+        },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(cells: &Self::Input) -> Result<Answer> {
+        Ok(part1(cells)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::load;
+    use crate::testing::{args, Lcg};
+
+    const INPUT: &str = "v...>>.vv>
+.vv>>.vv..
+>>.>v>...v
+>>v>>.>.v.
+v>v.vv.v..
+>.>>..v...
+.vv..>.>v.
+v.v..>>v.v
+....v..v.>";
+
+    #[test]
+    fn day25() {
+        assert_eq!(part1(&parse_input(INPUT).unwrap()).unwrap(), 58);
+        assert_eq!(
+            part1(&parse_input(&load(25).unwrap()).unwrap()).unwrap(),
+            419
+        );
+    }
+
+    #[test]
+    fn day25_simulation() {
+        let mut simulation = Simulation::new(&parse_input(INPUT).unwrap(), &Rules::default());
+
+        let states: Vec<_> = simulation.by_ref().collect();
+        assert_eq!(states.len(), 58);
+        assert_eq!(
+            states[1].1.to_string(),
+            "....>.>v.>
+v.v>.>v.v.
+>v>>..>v..
+>>v>v>.>.v
+.>v.v...v.
+v>>.>vvv..
+..v...>>..
+vv...>>vv.
+>.v.v..v.v"
+        );
+        assert_eq!(simulation.end(), Some(End::Stopped { step: 58 }));
+
+        // A full row of east facing cucumbers with a gap moves forever
+        let mut simulation = Simulation::new(&parse_input(">>.").unwrap(), &Rules::default());
+        assert_eq!(simulation.by_ref().count(), 3);
+        assert_eq!(
+            simulation.end(),
+            Some(End::Cycle {
+                start: 0,
+                length: 3
+            })
+        );
+        assert_eq!(
+            part1(&parse_input(">>.").unwrap()).unwrap_err().to_string(),
+            "the herds never stop, looping every 3 steps from step 0"
+        );
+    }
+
+    #[test]
+    fn day25_collision() {
+        let grid = parse_input(INPUT).unwrap();
+        let rules = Rules::default();
+
+        // Pretend the state after 2 steps has the same hash as the one after 1 step
+        let mut herd = Herd::from_grid(&grid, &rules);
+        herd.step();
+        herd.step();
+
+        let mut simulation = Simulation::new(&grid, &rules);
+        simulation.seen.insert(herd.fingerprint(), vec![1]);
+        while simulation.advance() {}
+        assert_eq!(simulation.end(), Some(End::Stopped { step: 58 }));
+    }
+
+    #[test]
+    fn day25_herd() {
+        let rules = [
+            Rules::default(),
+            "v:south,>:east".parse().unwrap(),
+            "<:west,^:north,>:east".parse().unwrap(),
+            Rules {
+                wrap: false,
+                ..">:east,<:west,v:south,^:north".parse().unwrap()
+            },
+        ];
+
+        // Pseudo random maps, with rows shorter than, as long as and longer than a word
+        let mut random = Lcg::new(25);
+        for rules in &rules {
+            for (width, height) in [(10, 9), (64, 5), (70, 3), (139, 137)] {
+                let grid = Grid::from_fn(width, height, |_| {
+                    let cell = (random.next() >> 59) as usize % (2 * rules.herds.len());
+                    (cell < rules.herds.len()).then_some(cell)
+                })
+                .wrapping(rules.wrap);
+
+                let mut herd = Herd::from_grid(&grid, rules);
+                let mut grid = grid;
+                for _ in 0..30 {
+                    let (next, updated) = run(grid, rules);
+                    assert_eq!(herd.step(), updated);
+                    assert_eq!(herd.to_grid(), next);
+                    grid = next;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn day25_rules() {
+        let rules: Rules = "<:west,^:north".parse().unwrap();
+        let grid = parse_map("..<\n^..", &rules).unwrap();
+        let mut herd = Herd::from_grid(&grid, &rules);
+
+        herd.step();
+        assert_eq!(herd.to_string(), "^<.\n...");
+        assert_eq!(
+            parse_map(">", &rules).unwrap_err().to_string(),
+            "line 1, column 1: expected '.', '<' or '^', found '>'"
+        );
+
+        // Without wrapping, the herds pile up against the borders
+        let rules = Rules {
+            wrap: false,
+            ..Rules::default()
+        };
+        let mut simulation = Simulation::new(&parse_map(">..\n.v.\n...", &rules).unwrap(), &rules);
+        while simulation.advance() {}
+        assert_eq!(simulation.herd.to_string(), "..>\n...\n.v.");
+        assert_eq!(simulation.end(), Some(End::Stopped { step: 3 }));
+
+        assert_eq!(
+            "a:up".parse::<Rules>().unwrap_err().to_string(),
+            "expected 'north', 'east', 'south' or 'west', found 'up'"
+        );
+        assert!("a:east,a:west".parse::<Rules>().is_err());
+        assert!(".:east".parse::<Rules>().is_err());
+
+        assert_eq!(
+            simulate_tool(">>.", &args(&["--no-wrap"])).unwrap(),
+            "nothing moves at step 3"
+        );
+    }
+
+    #[test]
+    fn day25_frames() {
+        assert_eq!(
+            frames_tool(">.\n..", &args(&["--every", "1"])).unwrap(),
+            "step 0:\n>.\n..\n\nstep 1:\n.>\n..\n\nthe herds loop every 2 steps from step 0"
+        );
+
+        let dir = std::env::temp_dir().join(format!("aoc2021-day25-{}", std::process::id()));
+        let out = frames_tool(
+            INPUT,
+            &args(&[
+                "--every",
+                "20",
+                "--format",
+                "ppm",
+                "--out",
+                dir.to_str().unwrap(),
+            ]),
+        )
+        .unwrap();
+        assert!(out.starts_with("wrote 3 frame(s)"));
+        assert!(out.ends_with("nothing moves at step 58"));
+        assert!(dir.join("step-00040.ppm").is_file());
+        std::fs::remove_dir_all(dir).unwrap();
+
+        assert!(frames_tool(INPUT, &args(&["--format", "png"])).is_err());
+    }
+
+    #[test]
+    fn day25_inputs() {
+        crate::answers::check_inputs(&Day25);
+    }
+}
@@ -0,0 +1,170 @@
+use crate::error::{Error, Result};
+use crate::parse;
+use crate::solution::{Answer, Solution};
+
+pub struct Report {
+    values: Vec<u32>,
+    value_len: u32,
+}
+
+fn insert_bit(value: u32, b: u32) -> u32 {
+    (value << 1) | b
+}
+
+fn parse_input(input: &str) -> Result<Report> {
+    let bits = parse::char_grid(input, "'0' or '1'", |c| c.to_digit(2))?;
+
+    let value_len = bits[0].len() as u32;
+    if value_len > u32::BITS {
+        return Err(Error::new(format!(
+            "values have {} bits, at most {} are supported",
+            value_len,
+            u32::BITS
+        )));
+    }
+
+    let values: Vec<_> = bits
+        .iter()
+        .map(|row| row.iter().fold(0, |v, &b| insert_bit(v, b)))
+        .collect();
+
+    Ok(Report { values, value_len })
+}
+
+fn part1(report: &Report) -> u64 {
+    let mut bits = vec![0; report.value_len as usize];
+
+    for value in &report.values {
+        for (idx, b) in bits.iter_mut().enumerate() {
+            *b += (value >> (report.value_len as usize - idx - 1)) & 1;
+        }
+    }
+
+    let mut gamma = 0;
+    let mut epsilon = 0;
+    let one_threshold = report.values.len() as u32 / 2;
+
+    for b in bits {
+        if b > one_threshold {
+            gamma = insert_bit(gamma, 1);
+            epsilon = insert_bit(epsilon, 0);
+        } else {
+            gamma = insert_bit(gamma, 0);
+            epsilon = insert_bit(epsilon, 1);
+        }
+    }
+
+    gamma as u64 * epsilon as u64
+}
+
+fn extract_value(
+    mut values: Vec<u32>,
+    value_len: u32,
+    rating: &str,
+    filter_cb: fn(u32, u32) -> u32,
+) -> Result<u32> {
+    for pos in 0..value_len {
+        if values.len() == 1 {
+            break;
+        }
+
+        let get_bit = |v, pos| (v >> (value_len - pos - 1)) & 1;
+
+        let ones = values.iter().map(|v| get_bit(v, pos)).sum();
+        let zeroes = values.len() as u32 - ones;
+
+        values = values
+            .iter()
+            .filter(|v| get_bit(v, pos) == filter_cb(ones, zeroes))
+            .cloned()
+            .collect();
+    }
+
+    values
+        .first()
+        .copied()
+        .ok_or_else(|| Error::new(format!("no value left for the {} rating", rating)))
+}
+
+fn part2(report: &Report) -> Result<u64> {
+    // Oxygen
+    let oxygen_filter_cb = |ones, zeroes| {
+        if ones >= zeroes {
+            1
+        } else {
+            0
+        }
+    };
+
+    let oxygen = extract_value(
+        report.values.clone(),
+        report.value_len,
+        "oxygen generator",
+        oxygen_filter_cb,
+    )?;
+
+    // CO2
+    let co2_filter_cb = |ones, zeroes| {
+        if zeroes <= ones {
+            0
+        } else {
+            1
+        }
+    };
+
+    let co2 = extract_value(
+        report.values.clone(),
+        report.value_len,
+        "CO2 scrubber",
+        co2_filter_cb,
+    )?;
+
+    Ok(oxygen as u64 * co2 as u64)
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Binary Diagnostic";
+
+    type Input = Report;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(report: &Self::Input) -> Result<Answer> {
+        Ok(part1(report).into())
+    }
+
+    fn part2(report: &Self::Input) -> Result<Option<Answer>> {
+        Ok(Some(part2(report)?.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn day3_ratings() {
+        // A single value is both ratings, duplicates leave none for the CO2 rating
+        assert_eq!(part2(&parse_input("1").unwrap()), Ok(1));
+        assert_eq!(
+            part2(&parse_input("1\n1").unwrap())
+                .unwrap_err()
+                .to_string(),
+            "no value left for the CO2 scrubber rating"
+        );
+
+        // Rates of 32 bits multiply beyond a u32
+        let report = parse_input(&"10".repeat(16)).unwrap();
+        assert_eq!(part1(&report), 0xaaaaaaaa * 0x55555555);
+    }
+
+    #[test]
+    fn day3_inputs() {
+        crate::answers::check_inputs(&Day3);
+    }
+}
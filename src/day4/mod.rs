@@ -1,7 +1,10 @@
-use std::fs;
-
 use colored::*;
 
+use crate::diag;
+use crate::error::{Error, Result};
+use crate::parse;
+use crate::solution::{Answer, Solution};
+
 const GRID_SIZE: u32 = 5;
 
 #[derive(Clone, Debug)]
@@ -11,7 +14,7 @@ struct GridItem {
 }
 
 #[derive(Clone, Debug)]
-struct Grid {
+pub struct Grid {
     items: Vec<GridItem>,
     last_marked: Option<u32>,
 }
@@ -19,7 +22,7 @@ struct Grid {
 impl Grid {
     fn mark(&mut self, b: u32) {
         let it = self.items.iter_mut().find(|x| x.value == b);
-        if let Some(mut item) = it {
+        if let Some(item) = it {
             item.marked = true;
             self.last_marked = Some(b);
         }
@@ -83,76 +86,93 @@ impl std::fmt::Display for Grid {
     }
 }
 
-fn str_it_to_u32<'a, T: Iterator<Item = &'a str>>(it: T) -> Vec<u32> {
-    it.map(|v| v.parse().unwrap()).collect()
+pub struct Bingo {
+    numbers: Vec<u32>,
+    grids: Vec<Grid>,
 }
 
-fn parse_input(input: &str) -> (Vec<u32>, Vec<Grid>) {
-    let mut lines = input.lines();
+fn parse_input(input: &str) -> Result<Bingo> {
+    let mut lines = parse::lines(input);
 
     // Get numbers
-    let numbers: Vec<_> = str_it_to_u32(lines.next().unwrap().split(','));
-    lines.next();
+    let first = lines.next().ok_or_else(|| Error::new("empty input"))?;
+    let numbers = first.parse_list(first.text, ',')?;
 
     // Get grids
     let mut grids = vec![];
+    let mut grid = vec![];
 
-    loop {
-        // Parse grid
-        let mut grid = vec![];
+    for l in lines {
+        if l.text.trim().is_empty() {
+            if !grid.is_empty() {
+                return Err(l.error_at(0, "incomplete grid"));
+            }
 
-        for l in (&mut lines).take(GRID_SIZE as usize) {
-            let mut parsed_lined = str_it_to_u32(l.split_whitespace());
-            grid.append(&mut parsed_lined);
+            continue;
         }
 
-        if grid.is_empty() {
-            break;
+        let row = l
+            .text
+            .split_whitespace()
+            .map(|v| l.parse(v))
+            .collect::<Result<Vec<u32>>>()?;
+
+        if row.len() != GRID_SIZE as usize {
+            return Err(l.error_at(
+                l.text.len(),
+                format!("expected {} numbers, found {}", GRID_SIZE, row.len()),
+            ));
         }
 
-        lines.next();
+        grid.extend(row);
 
         // Craft final grid
-        grids.push(Grid {
-            items: grid
-                .iter()
-                .map(|v| GridItem {
-                    value: *v,
-                    marked: false,
-                })
-                .collect(),
-            last_marked: None,
-        });
-    }
-
-    (numbers, grids)
+        if grid.len() == (GRID_SIZE * GRID_SIZE) as usize {
+            grids.push(Grid {
+                items: grid
+                    .drain(..)
+                    .map(|v| GridItem {
+                        value: v,
+                        marked: false,
+                    })
+                    .collect(),
+                last_marked: None,
+            });
+        }
+    }
+
+    if !grid.is_empty() {
+        return Err(Error::new("incomplete grid at end of input"));
+    }
+
+    Ok(Bingo { numbers, grids })
 }
 
-fn part1(input: &str) -> u32 {
-    let (numbers, mut grids) = parse_input(input);
+fn part1(bingo: &Bingo) -> Result<u32> {
+    let mut grids = bingo.grids.clone();
 
-    for n in numbers {
-        println!("Got number {}", n);
+    for &n in &bingo.numbers {
+        diag!("Got number {}", n);
 
         for grid in &mut grids {
             grid.mark(n);
 
             let (wins, score) = grid.wins();
             if wins {
-                println!("{}", grid);
-                return score * n;
+                diag!("{}", grid);
+                return Ok(score * n);
             }
         }
     }
 
-    0
+    Err(Error::new("no grid wins"))
 }
 
-fn part2(input: &str) -> u32 {
-    let (numbers, mut grids) = parse_input(input);
+fn part2(bingo: &Bingo) -> Result<u32> {
+    let mut grids = bingo.grids.clone();
     let mut winner: Option<Grid> = None;
 
-    for n in numbers {
+    for &n in &bingo.numbers {
         let mut winners_idx = vec![];
 
         for (idx, grid) in grids.iter_mut().enumerate() {
@@ -171,26 +191,39 @@ fn part2(input: &str) -> u32 {
     }
 
     if let Some(winner) = winner {
-        println!("Winner:");
-        println!("{}", winner);
-        return winner.score() * winner.last_marked().unwrap();
+        diag!("Winner:\n{}", winner);
+        return Ok(winner.score() * winner.last_marked().unwrap());
     }
 
-    0
+    Err(Error::new("no grid wins"))
 }
 
-fn main() {
-    let input = fs::read_to_string("src/day4/input.txt").unwrap();
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Giant Squid";
+
+    type Input = Bingo;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(bingo: &Self::Input) -> Result<Answer> {
+        Ok(part1(bingo)?.into())
+    }
+
+    fn part2(bingo: &Self::Input) -> Result<Option<Answer>> {
+        Ok(Some(part2(bingo)?.into()))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &'static str =
-        "7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+    const INPUT: &str = "7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
 
     22 13 17 11  0
     8   2 23  4 24
@@ -212,11 +245,16 @@ mod tests {
 
     #[test]
     fn day4_part1() {
-        assert_eq!(part1(INPUT), 4512);
+        assert_eq!(part1(&parse_input(INPUT).unwrap()), Ok(4512));
     }
 
     #[test]
     fn day4_part2() {
-        assert_eq!(part2(INPUT), 1924);
+        assert_eq!(part2(&parse_input(INPUT).unwrap()), Ok(1924));
+    }
+
+    #[test]
+    fn day4_inputs() {
+        crate::answers::check_inputs(&Day4);
     }
 }
@@ -0,0 +1,147 @@
+use std::collections::HashMap;
+
+use crate::error::Result;
+use crate::geometry::Point2;
+use crate::parse::{self, Line};
+use crate::solution::{Answer, Solution};
+
+#[derive(Clone, Debug)]
+pub struct Segment {
+    origin: Point2,
+    end: Point2,
+}
+
+impl Segment {
+    fn parse(line: &Line) -> Result<Self> {
+        let (origin, end) = line.split_once(line.text, " -> ")?;
+        let segment = Segment {
+            origin: Point2::parse(line, origin)?,
+            end: Point2::parse(line, end)?,
+        };
+
+        // Diagonals are always at 45 degrees
+        let dx = (segment.end.x - segment.origin.x).abs();
+        let dy = (segment.end.y - segment.origin.y).abs();
+        if dx != 0 && dy != 0 && dx != dy {
+            return Err(line.error_at(0, "segment is not horizontal, vertical or diagonal"));
+        }
+
+        Ok(segment)
+    }
+
+    fn vert_or_horiz(&self) -> bool {
+        self.origin.x == self.end.x || self.origin.y == self.end.y
+    }
+}
+
+fn score<'a>(segments: impl Iterator<Item = &'a Segment>) -> u32 {
+    let mut diagram = HashMap::new();
+
+    let mut update_diagram = |p: Point2| {
+        diagram.entry(p).and_modify(|e| *e += 1).or_insert(1);
+    };
+
+    for segment in segments {
+        let increment = (segment.end - segment.origin).signum();
+
+        let mut current = segment.origin;
+        while current != segment.end {
+            update_diagram(current);
+
+            current += increment;
+        }
+
+        update_diagram(current);
+    }
+
+    diagram
+        .iter()
+        .filter_map(|(_, &v)| if v > 1 { Some(v) } else { None })
+        .count() as u32
+}
+
+fn parse_input(input: &str) -> Result<Vec<Segment>> {
+    parse::lines(input).map(|l| Segment::parse(&l)).collect()
+}
+
+fn part1(segments: &[Segment]) -> u32 {
+    score(segments.iter().filter(|s| s.vert_or_horiz()))
+}
+
+fn part2(segments: &[Segment]) -> u32 {
+    score(segments.iter())
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Hydrothermal Venture";
+
+    type Input = Vec<Segment>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(segments: &Self::Input) -> Result<Answer> {
+        Ok(part1(segments).into())
+    }
+
+    fn part2(segments: &Self::Input) -> Result<Option<Answer>> {
+        Ok(Some(part2(segments).into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::load;
+
+    const INPUT: &str = "0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2";
+
+    #[test]
+    fn day5_part1() {
+        assert_eq!(part1(&parse_input(INPUT).unwrap()), 5);
+        assert_eq!(part1(&parse_input(&load(5).unwrap()).unwrap()), 6687);
+    }
+
+    #[test]
+    fn day5_part2() {
+        assert_eq!(part2(&parse_input(INPUT).unwrap()), 12);
+        assert_eq!(part2(&parse_input(&load(5).unwrap()).unwrap()), 19851);
+    }
+
+    #[test]
+    fn day5_errors() {
+        let err = |input| parse_input(input).unwrap_err().to_string();
+
+        assert_eq!(
+            err("0,9 -> 5,9\n8,0 -> 0,a"),
+            "line 2, column 10: expected i32, found 'a'"
+        );
+        assert_eq!(err("0,9 - 5,9"), "line 1, column 1: expected ' -> '");
+        assert_eq!(
+            err("0,9 -> 5"),
+            "line 1, column 8: expected 2 coordinates, found 1"
+        );
+        assert_eq!(
+            err("0,0 -> 1,2"),
+            "line 1, column 1: segment is not horizontal, vertical or diagonal"
+        );
+    }
+
+    #[test]
+    fn day5_inputs() {
+        crate::answers::check_inputs(&Day5);
+    }
+}
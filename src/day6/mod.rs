@@ -0,0 +1,96 @@
+use crate::error::{Error, Result};
+use crate::parse;
+use crate::solution::{Answer, Solution};
+
+fn parse_input(input: &str) -> Result<Vec<usize>> {
+    let line = parse::lines(input)
+        .next()
+        .ok_or_else(|| Error::new("empty input"))?;
+
+    line.text
+        .split(',')
+        .map(|x| {
+            let timer = line.parse(x)?;
+            if timer > 8 {
+                return Err(line.error(x, format!("timer {} is above 8", timer)));
+            }
+
+            Ok(timer)
+        })
+        .collect()
+}
+
+fn compute_input(timers: &[usize], days: u32) -> u64 {
+    let mut fishes: [u64; 9] = [0; 9];
+    for &fish in timers {
+        fishes[fish] += 1;
+    }
+
+    let mut idx: usize = 0;
+
+    for _ in 0..days {
+        let front = fishes[idx];
+        idx = (idx + 1) % 9;
+
+        fishes[(idx + 6) % 9] += front;
+        fishes[(idx + 8) % 9] = front;
+    }
+
+    fishes.iter().sum()
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Lanternfish";
+
+    type Input = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(timers: &Self::Input) -> Result<Answer> {
+        Ok(compute_input(timers, 80).into())
+    }
+
+    fn part2(timers: &Self::Input) -> Result<Option<Answer>> {
+        Ok(Some(compute_input(timers, 256).into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::load;
+
+    const INPUT: &str = "3,4,3,1,2";
+
+    #[test]
+    fn day6_part1() {
+        assert_eq!(compute_input(&parse_input(INPUT).unwrap(), 18), 26);
+        assert_eq!(compute_input(&parse_input(INPUT).unwrap(), 80), 5934);
+        assert_eq!(
+            compute_input(&parse_input(&load(6).unwrap()).unwrap(), 80),
+            356190
+        );
+    }
+
+    #[test]
+    fn day6_part2() {
+        assert_eq!(
+            compute_input(&parse_input(INPUT).unwrap(), 256),
+            26984457539
+        );
+        assert_eq!(
+            compute_input(&parse_input(&load(6).unwrap()).unwrap(), 256),
+            1617359101538
+        );
+    }
+
+    #[test]
+    fn day6_inputs() {
+        crate::answers::check_inputs(&Day6);
+    }
+}
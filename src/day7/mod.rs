@@ -0,0 +1,106 @@
+use crate::error::{Error, Result};
+use crate::parse;
+use crate::solution::{Answer, Solution};
+
+fn abs_diff(x: u32, y: u32) -> u64 {
+    x.abs_diff(y) as u64
+}
+
+fn parse_input(input: &str) -> Result<Vec<u32>> {
+    let line = parse::lines(input)
+        .next()
+        .ok_or_else(|| Error::new("empty input"))?;
+
+    line.parse_list(line.text, ',')
+}
+
+fn find_shortest_path(crabs: &[u32], distance_cb: fn(pos: u32, target: u32) -> u64) -> u128 {
+    let fuel = |target| {
+        crabs
+            .iter()
+            .map(|&x| distance_cb(x, target) as u128)
+            .sum::<u128>()
+    };
+
+    // The fuel is a convex function of the target, so it decreases down to its minimum and
+    // only increases after it
+    let mut low = *crabs.iter().min().unwrap();
+    let mut high = *crabs.iter().max().unwrap();
+
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if fuel(mid) <= fuel(mid + 1) {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+
+    fuel(low)
+}
+
+fn part1(crabs: &[u32]) -> u128 {
+    find_shortest_path(crabs, abs_diff)
+}
+
+fn part2(crabs: &[u32]) -> u128 {
+    find_shortest_path(crabs, |pos, target| {
+        let dist = abs_diff(pos, target);
+        (dist * (dist + 1)) / 2
+    })
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "The Treachery of Whales";
+
+    type Input = Vec<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(crabs: &Self::Input) -> Result<Answer> {
+        Ok(part1(crabs).into())
+    }
+
+    fn part2(crabs: &Self::Input) -> Result<Option<Answer>> {
+        Ok(Some(part2(crabs).into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::load;
+
+    const INPUT: &str = "16,1,2,0,4,2,7,1,2,14";
+
+    #[test]
+    fn day7_part1() {
+        assert_eq!(part1(&parse_input(INPUT).unwrap()), 37);
+        assert_eq!(part1(&parse_input(&load(7).unwrap()).unwrap()), 344138);
+    }
+
+    #[test]
+    fn day7_part2() {
+        assert_eq!(part2(&parse_input(INPUT).unwrap()), 168);
+        assert_eq!(part2(&parse_input(&load(7).unwrap()).unwrap()), 94862124);
+    }
+
+    #[test]
+    fn day7_far_crabs() {
+        assert_eq!(part1(&parse_input("1,2,3,99999").unwrap()), 99999);
+        assert_eq!(
+            part2(&parse_input("4294967295,1").unwrap()),
+            4611686016279904256
+        );
+    }
+
+    #[test]
+    fn day7_inputs() {
+        crate::answers::check_inputs(&Day7);
+    }
+}
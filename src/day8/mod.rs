@@ -0,0 +1,209 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::error::{Error, Result};
+use crate::parse::{self, Line};
+use crate::solution::{Answer, Solution};
+
+/// Signal patterns and output value digits of one display.
+pub type Note = (Vec<String>, Vec<String>);
+
+fn parse_patterns(line: &Line, patterns: &str, count: usize) -> Result<Vec<String>> {
+    let patterns = patterns
+        .split(' ')
+        .map(|s| {
+            if s.is_empty() || !s.chars().all(|c| ('a'..='g').contains(&c)) {
+                return Err(line.error(s, format!("invalid pattern '{}'", s)));
+            }
+
+            let mut v: Vec<_> = s.chars().collect();
+            v.sort_unstable();
+            Ok(String::from_iter(v))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    if patterns.len() != count {
+        return Err(line.error_at(
+            line.text.len(),
+            format!("expected {} patterns, found {}", count, patterns.len()),
+        ));
+    }
+
+    Ok(patterns)
+}
+
+fn parse_input(input: &str) -> Result<Vec<Note>> {
+    parse::lines(input)
+        .map(|l| {
+            let (patterns, output) = l.split_once(l.text, " | ")?;
+
+            Ok((
+                parse_patterns(&l, patterns, 10)?,
+                parse_patterns(&l, output, 4)?,
+            ))
+        })
+        .collect()
+}
+
+fn part1(notes: &[Note]) -> u32 {
+    notes
+        .iter()
+        .flat_map(|(_, output)| output)
+        .filter(|&x| {
+            let l = x.len();
+            l == 2 || l == 3 || l == 4 || l == 7
+        })
+        .count() as u32
+}
+
+fn solve_problem(str_input: &[String], output: &[String]) -> Result<u32> {
+    let str_to_hashset = |input: &str| -> HashSet<char> { input.chars().collect() };
+    let input: Vec<_> = str_input.iter().map(|x| str_to_hashset(x)).collect();
+    let missing = |digit| Error::new(format!("cannot identify the pattern of {}", digit));
+
+    // Find easy ones
+    let one = input
+        .iter()
+        .find(|&x| x.len() == 2)
+        .ok_or_else(|| missing("one"))?;
+    let four = input
+        .iter()
+        .find(|&x| x.len() == 4)
+        .ok_or_else(|| missing("four"))?;
+    let seven = input
+        .iter()
+        .find(|&x| x.len() == 3)
+        .ok_or_else(|| missing("seven"))?;
+    let eight = input
+        .iter()
+        .find(|&x| x.len() == 7)
+        .ok_or_else(|| missing("eight"))?;
+
+    // Find nine
+    let nine = input
+        .iter()
+        .find(|&x| {
+            let merged: HashSet<_> = four.union(seven).copied().collect();
+            x.len() == 6 && x.difference(&merged).count() == 1
+        })
+        .ok_or_else(|| missing("nine"))?;
+
+    // Find two
+    let two = input
+        .iter()
+        .find(|&x| x.len() == 5 && x.difference(nine).count() == 1)
+        .ok_or_else(|| missing("two"))?;
+
+    // Find zero
+    let zero = input
+        .iter()
+        .find(|&x| x.len() == 6 && x != nine && x.intersection(one).count() == 2)
+        .ok_or_else(|| missing("zero"))?;
+
+    // Find six
+    let six = input
+        .iter()
+        .find(|&x| x.len() == 6 && x != nine && x != zero)
+        .ok_or_else(|| missing("six"))?;
+
+    // Find five
+    let five = input
+        .iter()
+        .find(|&x| x.len() == 5 && x != two && x.intersection(six).count() == 5)
+        .ok_or_else(|| missing("five"))?;
+
+    // Find three
+    let three = input
+        .iter()
+        .find(|&x| x.len() == 5 && x != two && x.intersection(six).count() == 4)
+        .ok_or_else(|| missing("three"))?;
+
+    // Build final map from results
+    let map: HashMap<_, _> = [zero, one, two, three, four, five, six, seven, eight, nine]
+        .iter()
+        .enumerate()
+        .map(|(digit, &r)| {
+            // Find HashSet position in the input to get the original form
+            let result_idx = str_input
+                .iter()
+                .enumerate()
+                .find_map(|(i, x)| {
+                    if str_to_hashset(x) == *r {
+                        Some(i)
+                    } else {
+                        None
+                    }
+                })
+                .unwrap();
+
+            (str_input[result_idx].clone(), digit)
+        })
+        .collect();
+
+    // Compute result
+    output.iter().try_fold(0, |acc, x| {
+        let digit = map
+            .get(x)
+            .ok_or_else(|| Error::new(format!("output pattern '{}' is not a digit", x)))?;
+
+        Ok(acc * 10 + *digit as u32)
+    })
+}
+
+fn part2(notes: &[Note]) -> Result<u32> {
+    notes.iter().map(|(x, y)| solve_problem(x, y)).sum()
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Seven Segment Search";
+
+    type Input = Vec<Note>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(notes: &Self::Input) -> Result<Answer> {
+        Ok(part1(notes).into())
+    }
+
+    fn part2(notes: &Self::Input) -> Result<Option<Answer>> {
+        Ok(Some(part2(notes)?.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::load;
+    const INPUT: &str =
+        "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
+edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
+fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg
+fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb
+aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea
+fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb
+dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe
+bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
+egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
+gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce";
+
+    #[test]
+    fn day8_part1() {
+        assert_eq!(part1(&parse_input(INPUT).unwrap()), 26);
+        assert_eq!(part1(&parse_input(&load(8).unwrap()).unwrap()), 521);
+    }
+
+    #[test]
+    fn day8_part2() {
+        assert_eq!(part2(&parse_input(INPUT).unwrap()), Ok(61229));
+        assert_eq!(part2(&parse_input(&load(8).unwrap()).unwrap()), Ok(1016804));
+    }
+
+    #[test]
+    fn day8_inputs() {
+        crate::answers::check_inputs(&Day8);
+    }
+}
@@ -0,0 +1,106 @@
+use crate::error::Result;
+use crate::grid::{Grid, Pos};
+use crate::solution::{Answer, Solution};
+
+fn parse_input(input: &str) -> Result<Grid<u32>> {
+    Grid::parse_digits(input)
+}
+
+fn part1(heightmap: &Grid<u32>) -> u32 {
+    let mut risk = 0;
+
+    for (pos, &current) in heightmap.iter() {
+        let is_low_point = heightmap
+            .neighbours4(pos)
+            .all(|next| current < heightmap[next]);
+
+        if is_low_point {
+            risk += current + 1;
+        }
+    }
+
+    risk
+}
+
+fn part2(heightmap: &Grid<u32>) -> u32 {
+    // Mark 9 as already visited
+    let mut hitmap = heightmap.map(|&height| height == 9);
+
+    let mut bassins_size: Vec<u32> = vec![];
+
+    for pos in heightmap.positions() {
+        if hitmap[pos] {
+            continue;
+        }
+
+        hitmap[pos] = true;
+
+        let mut bassin_size = 0;
+        let mut next_positions: Vec<Pos> = vec![pos];
+
+        while let Some(pos) = next_positions.pop() {
+            bassin_size += 1;
+
+            for next in heightmap.neighbours4(pos) {
+                if !hitmap[next] {
+                    hitmap[next] = true;
+                    next_positions.push(next);
+                }
+            }
+        }
+
+        bassins_size.push(bassin_size);
+    }
+
+    bassins_size.sort_by(|a, b| b.cmp(a));
+    bassins_size.iter().take(3).product()
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Smoke Basin";
+
+    type Input = Grid<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(heightmap: &Self::Input) -> Result<Answer> {
+        Ok(part1(heightmap).into())
+    }
+
+    fn part2(heightmap: &Self::Input) -> Result<Option<Answer>> {
+        Ok(Some(part2(heightmap).into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::load;
+    const INPUT: &str = "2199943210
+3987894921
+9856789892
+8767896789
+9899965678";
+
+    #[test]
+    fn day9_part1() {
+        assert_eq!(part1(&parse_input(INPUT).unwrap()), 15);
+        assert_eq!(part1(&parse_input(&load(9).unwrap()).unwrap()), 548);
+    }
+
+    #[test]
+    fn day9_part2() {
+        assert_eq!(part2(&parse_input(INPUT).unwrap()), 1134);
+        assert_eq!(part2(&parse_input(&load(9).unwrap()).unwrap()), 786048);
+    }
+
+    #[test]
+    fn day9_inputs() {
+        crate::answers::check_inputs(&Day9);
+    }
+}
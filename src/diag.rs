@@ -0,0 +1,23 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static VERBOSE: AtomicBool = AtomicBool::new(false);
+
+/// Enable the diagnostics printed by the solvers, off by default.
+pub fn set_verbose(verbose: bool) {
+    VERBOSE.store(verbose, Ordering::Relaxed);
+}
+
+pub fn verbose() -> bool {
+    VERBOSE.load(Ordering::Relaxed)
+}
+
+/// Print a diagnostic of a solver, like an intermediate state, to stderr when verbose output
+/// is enabled. Stdout is kept for the answers.
+#[macro_export]
+macro_rules! diag {
+    ($($arg:tt)*) => {
+        if $crate::diag::verbose() {
+            eprintln!($($arg)*);
+        }
+    };
+}
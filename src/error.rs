@@ -0,0 +1,55 @@
+use std::fmt;
+
+/// Location of an error in the puzzle input, both 1-based.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// Error raised while parsing or solving a puzzle.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Error {
+    message: String,
+    position: Option<Position>,
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    /// Error that cannot be tied to a location in the input, like an input without solution.
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            position: None,
+        }
+    }
+
+    pub fn at(line: usize, column: usize, message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            position: Some(Position { line, column }),
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn position(&self) -> Option<Position> {
+        self.position
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.position {
+            Some(Position { line, column }) => {
+                write!(f, "line {}, column {}: {}", line, column, self.message)
+            }
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,180 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+use crate::error::{Error, Result};
+use crate::parse::Line;
+
+/// Point or vector of the plane.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Point2 {
+    pub x: i32,
+    pub y: i32,
+}
+
+/// Point or vector of the space.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Point3 {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+// Implement the arithmetic shared by both points, component by component
+macro_rules! impl_point {
+    ($point:ident, $dimensions:expr, $($field:ident),+) => {
+        impl $point {
+            pub const ORIGIN: Self = Self { $($field: 0),+ };
+
+            pub const fn new($($field: i32),+) -> Self {
+                Self { $($field),+ }
+            }
+
+            /// Sum of the distances along each axis.
+            pub fn manhattan(&self, other: &Self) -> i32 {
+                0 $(+ (self.$field - other.$field).abs())+
+            }
+
+            /// Largest of the distances along each axis, the number of king moves.
+            pub fn chebyshev(&self, other: &Self) -> i32 {
+                0i32 $(.max((self.$field - other.$field).abs()))+
+            }
+
+            /// Unit step towards the direction of the vector along each axis.
+            pub fn signum(&self) -> Self {
+                Self { $($field: self.$field.signum()),+ }
+            }
+
+            /// Parse `part`, a slice of `line` holding comma separated coordinates.
+            pub fn parse<'a>(line: &Line<'a>, part: &'a str) -> Result<Self> {
+                let values: Vec<i32> = line.parse_list(part, ',')?;
+
+                match values[..] {
+                    [$($field),+] => Ok(Self { $($field),+ }),
+                    _ => Err(line.error(
+                        part,
+                        format!(
+                            "expected {} coordinates, found {}",
+                            $dimensions,
+                            values.len()
+                        ),
+                    )),
+                }
+            }
+        }
+
+        impl Add for $point {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Self { $($field: self.$field + other.$field),+ }
+            }
+        }
+
+        impl Sub for $point {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                Self { $($field: self.$field - other.$field),+ }
+            }
+        }
+
+        impl Neg for $point {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $($field: -self.$field),+ }
+            }
+        }
+
+        impl Mul<i32> for $point {
+            type Output = Self;
+
+            fn mul(self, factor: i32) -> Self {
+                Self { $($field: self.$field * factor),+ }
+            }
+        }
+
+        impl AddAssign for $point {
+            fn add_assign(&mut self, other: Self) {
+                $(self.$field += other.$field;)+
+            }
+        }
+
+        impl SubAssign for $point {
+            fn sub_assign(&mut self, other: Self) {
+                $(self.$field -= other.$field;)+
+            }
+        }
+
+        impl FromStr for $point {
+            type Err = Error;
+
+            fn from_str(s: &str) -> Result<Self> {
+                Self::parse(&Line { number: 1, text: s }, s)
+                    .map_err(|e| Error::new(e.message()))
+            }
+        }
+    };
+}
+
+impl_point!(Point2, 2, x, y);
+impl_point!(Point3, 3, x, y, z);
+
+impl fmt::Display for Point2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl fmt::Display for Point3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn point_ops() {
+        let a = Point2::new(1, -2);
+        let b = Point2::new(4, 2);
+
+        assert_eq!(a + b, Point2::new(5, 0));
+        assert_eq!(b - a, Point2::new(3, 4));
+        assert_eq!(-a * 2, Point2::new(-2, 4));
+        assert_eq!((b - a).signum(), Point2::new(1, 1));
+        assert_eq!(a.manhattan(&b), 7);
+        assert_eq!(a.chebyshev(&b), 4);
+
+        let mut c = Point3::new(1, 2, 3);
+        c += Point3::new(1, 1, 1);
+        c -= Point3::new(0, 0, 4);
+        assert_eq!(c, Point3::new(2, 3, 0));
+        assert_eq!(c.manhattan(&Point3::ORIGIN), 5);
+    }
+
+    #[test]
+    fn point_parse() {
+        assert_eq!("3,-4".parse(), Ok(Point2::new(3, -4)));
+        assert_eq!("1,2,3".parse(), Ok(Point3::new(1, 2, 3)));
+        assert_eq!(
+            "1,2".parse::<Point3>().unwrap_err().to_string(),
+            "expected 3 coordinates, found 2"
+        );
+
+        let line = Line {
+            number: 4,
+            text: "0,9 -> 5,x",
+        };
+        assert_eq!(
+            Point2::parse(&line, &line.text[7..])
+                .unwrap_err()
+                .to_string(),
+            "line 4, column 10: expected i32, found 'x'"
+        );
+        assert_eq!(Point2::new(5, 9).to_string(), "5,9");
+    }
+}
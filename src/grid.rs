@@ -0,0 +1,271 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::error::{Error, Result};
+use crate::parse;
+
+/// Position of a cell, as `(x, y)` with `y` growing downwards.
+pub type Pos = (usize, usize);
+
+/// Offsets of the 4 orthogonal neighbours.
+pub const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets of the 8 neighbours, diagonals included.
+pub const ADJACENT: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// Rectangular map stored row by row.
+///
+/// Indexing with a [`Pos`] panics out of the map, like slices do; [`Grid::get`] and the
+/// neighbour iterators are bounds-checked instead. A wrapping grid is a torus: moving past one
+/// edge comes back through the opposite one.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+    wrap: bool,
+}
+
+impl<T> Grid<T> {
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(Pos) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(&mut cell)
+            .collect();
+
+        Self {
+            width,
+            height,
+            cells,
+            wrap: false,
+        }
+    }
+
+    /// Build a grid from its rows, which must all have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+
+        if width == 0 {
+            return Err(Error::new("empty map"));
+        }
+        if let Some(y) = rows.iter().position(|r| r.len() != width) {
+            return Err(Error::new(format!(
+                "row {} has {} cells instead of {}",
+                y,
+                rows[y].len(),
+                width
+            )));
+        }
+
+        Ok(Self {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+            wrap: false,
+        })
+    }
+
+    /// Parse a map of characters, see [`parse::char_grid`].
+    pub fn parse(input: &str, expected: &str, cell: impl Fn(char) -> Option<T>) -> Result<Self> {
+        Self::from_rows(parse::char_grid(input, expected, cell)?)
+    }
+
+    /// Make moves past the edges wrap around to the opposite edge.
+    pub fn wrapping(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, (x, y): Pos) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.1 * self.width + pos.0])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.1 * self.width + pos.0])
+        } else {
+            None
+        }
+    }
+
+    /// Position reached by moving `(dx, dy)` from `pos`, if it is still on the map.
+    pub fn offset(&self, (x, y): Pos, (dx, dy): (isize, isize)) -> Option<Pos> {
+        let x = x as isize + dx;
+        let y = y as isize + dy;
+
+        if self.wrap {
+            let x = x.rem_euclid(self.width as isize) as usize;
+            let y = y.rem_euclid(self.height as isize) as usize;
+
+            Some((x, y))
+        } else if 0 <= x && x < self.width as isize && 0 <= y && y < self.height as isize {
+            Some((x as usize, y as usize))
+        } else {
+            None
+        }
+    }
+
+    /// Positions reached by each of `offsets` from `pos`, skipping the ones off the map.
+    pub fn neighbours<'a>(
+        &'a self,
+        pos: Pos,
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = Pos> + 'a {
+        offsets.iter().filter_map(move |&d| self.offset(pos, d))
+    }
+
+    /// Up, right, down and left neighbours.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.neighbours(pos, &ORTHOGONAL)
+    }
+
+    /// All 8 neighbours, diagonals included.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.neighbours(pos, &ADJACENT)
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn cells_mut(&mut self) -> &mut [T] {
+        &mut self.cells
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+            wrap: self.wrap,
+        }
+    }
+}
+
+impl Grid<u32> {
+    /// Parse a map of digits.
+    pub fn parse_digits(input: &str) -> Result<Self> {
+        Self::from_rows(parse::digit_grid(input)?)
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos).unwrap_or_else(|| {
+            panic!(
+                "position {:?} out of a {}x{} grid",
+                pos, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("position {:?} out of a {}x{} grid", pos, width, height))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grid_access() {
+        let mut grid = Grid::parse_digits("123\n456").unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get((0, 2)), None);
+
+        grid[(0, 0)] = 9;
+        assert_eq!(grid.to_string(), "923\n456");
+        assert_eq!(grid.rows().nth(1), Some(&[4, 5, 6][..]));
+
+        assert!(Grid::<u32>::from_rows(vec![vec![1], vec![]]).is_err());
+        assert!(Grid::parse_digits("12\n3").is_err());
+    }
+
+    #[test]
+    fn grid_neighbours() {
+        let grid = Grid::from_fn(3, 3, |(x, y)| x + 3 * y);
+
+        let mut n: Vec<_> = grid.neighbours4((0, 0)).collect();
+        n.sort_unstable();
+        assert_eq!(n, vec![(0, 1), (1, 0)]);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        assert_eq!(grid.neighbours8((2, 2)).count(), 3);
+
+        let grid = grid.wrapping(true);
+        assert_eq!(grid.offset((0, 0), (-1, 0)), Some((2, 0)));
+        assert_eq!(grid.offset((2, 2), (1, 1)), Some((0, 0)));
+        assert_eq!(grid.neighbours8((0, 0)).count(), 8);
+    }
+}
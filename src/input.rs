@@ -0,0 +1,201 @@
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Environment variable used when no input is given on the command line.
+pub const INPUT_ENV: &str = "AOC_INPUT";
+
+/// Name of the main input of a day, the one read when no name is given.
+pub const DEFAULT_NAME: &str = "input";
+
+/// Where to read the puzzle input from.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Source {
+    /// Standard input, spelled `-` on the command line.
+    Stdin,
+    /// A single input file, whatever the day.
+    File(PathBuf),
+    /// A directory holding the inputs of every day, either as `day<N>/input.txt` (the layout of
+    /// this repository) or as `day<N>.txt`. Other named inputs, like the puzzle examples, are
+    /// stored as `day<N>/<name>.txt` or `day<N>-<name>.txt`.
+    Dir(PathBuf),
+}
+
+impl Source {
+    pub fn parse(arg: &str) -> Self {
+        let path = Path::new(arg);
+
+        if arg == "-" {
+            Source::Stdin
+        } else if path.is_dir() {
+            Source::Dir(path.to_path_buf())
+        } else {
+            Source::File(path.to_path_buf())
+        }
+    }
+
+    pub fn from_env() -> Option<Self> {
+        env::var(INPUT_ENV)
+            .ok()
+            .filter(|v| !v.is_empty())
+            .map(|v| Self::parse(&v))
+    }
+
+    /// Pick the source given on the command line, then the one from the environment, then the
+    /// inputs bundled with the crate.
+    pub fn resolve(arg: Option<&str>) -> Self {
+        arg.map(Self::parse)
+            .or_else(Self::from_env)
+            .unwrap_or_default()
+    }
+
+    /// Whether the source can provide the input of more than one day.
+    pub fn is_dir(&self) -> bool {
+        matches!(self, Source::Dir(_))
+    }
+
+    pub fn read(&self, day: u8) -> io::Result<String> {
+        self.read_named(day, DEFAULT_NAME)
+    }
+
+    /// Read the input called `name` of a day. Only directories hold more than one input.
+    pub fn read_named(&self, day: u8, name: &str) -> io::Result<String> {
+        match self {
+            Source::Stdin | Source::File(_) if name != DEFAULT_NAME => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("named input '{}' requires a directory of inputs", name),
+            )),
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+            Source::File(path) => read_file(path),
+            Source::Dir(dir) => {
+                let nested = dir
+                    .join(format!("day{}", day))
+                    .join(format!("{}.txt", name));
+
+                if nested.is_file() {
+                    read_file(&nested)
+                } else if name == DEFAULT_NAME {
+                    read_file(&dir.join(format!("day{}.txt", day)))
+                } else {
+                    read_file(&dir.join(format!("day{}-{}.txt", day, name)))
+                }
+            }
+        }
+    }
+
+    /// Names of the inputs available for a day, the default one first.
+    pub fn names(&self, day: u8) -> Vec<String> {
+        let dir = match self {
+            Source::Dir(dir) => dir,
+            _ => return vec![DEFAULT_NAME.to_string()],
+        };
+
+        let mut names: Vec<String> = vec![];
+
+        let nested = fs::read_dir(dir.join(format!("day{}", day)))
+            .into_iter()
+            .flatten()
+            .flatten()
+            .filter_map(|e| e.file_name().into_string().ok())
+            .filter_map(|f| f.strip_suffix(".txt").map(String::from));
+        names.extend(nested);
+
+        let prefix = format!("day{}", day);
+        let flat = fs::read_dir(dir)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .filter_map(|e| e.file_name().into_string().ok())
+            .filter_map(|f| {
+                let name = f.strip_prefix(&prefix)?.strip_suffix(".txt")?;
+
+                match name.strip_prefix('-') {
+                    Some(name) => Some(name.to_string()),
+                    None if name.is_empty() => Some(DEFAULT_NAME.to_string()),
+                    None => None,
+                }
+            });
+        names.extend(flat);
+
+        names.sort_by(|a, b| (a != DEFAULT_NAME, a).cmp(&(b != DEFAULT_NAME, b)));
+        names.dedup();
+        names
+    }
+}
+
+impl Default for Source {
+    fn default() -> Self {
+        Source::Dir(bundled_dir())
+    }
+}
+
+/// Directory of the inputs shipped with the crate, usable whatever the working directory.
+pub fn bundled_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("src")
+}
+
+/// Read the bundled input of a day.
+pub fn load(day: u8) -> io::Result<String> {
+    Source::default().read(day)
+}
+
+/// Read a bundled named input of a day.
+pub fn load_named(day: u8, name: &str) -> io::Result<String> {
+    Source::default().read_named(day, name)
+}
+
+fn read_file(path: &Path) -> io::Result<String> {
+    fs::read_to_string(path)
+        .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn source_parse() {
+        assert_eq!(Source::parse("-"), Source::Stdin);
+        assert_eq!(
+            Source::parse(bundled_dir().to_str().unwrap()),
+            Source::default()
+        );
+        assert_eq!(
+            Source::parse("day5.txt"),
+            Source::File(PathBuf::from("day5.txt"))
+        );
+    }
+
+    #[test]
+    fn source_read() {
+        let dir = env::temp_dir().join(format!("aoc2021-input-{}", std::process::id()));
+        fs::create_dir_all(dir.join("day2")).unwrap();
+        fs::write(dir.join("day1.txt"), "flat").unwrap();
+        fs::write(dir.join("day1-example.txt"), "flat example").unwrap();
+        fs::write(dir.join("day2").join("input.txt"), "nested").unwrap();
+        fs::write(dir.join("day2").join("alice.txt"), "nested alice").unwrap();
+
+        let source = Source::Dir(dir.clone());
+        assert_eq!(source.read(1).unwrap(), "flat");
+        assert_eq!(source.read(2).unwrap(), "nested");
+        assert!(source.read(3).is_err());
+
+        assert_eq!(source.read_named(1, "example").unwrap(), "flat example");
+        assert_eq!(source.read_named(2, "alice").unwrap(), "nested alice");
+        assert!(source.read_named(2, "bob").is_err());
+        assert_eq!(source.names(1), vec!["input", "example"]);
+        assert_eq!(source.names(2), vec!["input", "alice"]);
+        assert!(source.names(3).is_empty());
+
+        let source = Source::File(dir.join("day1.txt"));
+        assert_eq!(source.read(2).unwrap(), "flat");
+        assert!(source.read_named(2, "alice").is_err());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
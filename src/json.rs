@@ -0,0 +1,36 @@
+use std::fmt::Write;
+
+/// Quote and escape `s` as a JSON string.
+pub fn string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_string() {
+        assert_eq!(string("abc"), r#""abc""#);
+        assert_eq!(string("#.\n.#"), r##""#.\n.#""##);
+        assert_eq!(string("a \"b\" \\ \u{1}"), r#""a \"b\" \\ \u0001""#);
+    }
+}
@@ -0,0 +1,45 @@
+pub mod answers;
+pub mod automaton;
+pub mod bench;
+pub mod cuboid;
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day2;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod diag;
+pub mod error;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod json;
+pub mod parse;
+pub mod pixmap;
+pub mod registry;
+pub mod solution;
+#[cfg(test)]
+mod testing;
+pub mod tool_args;
+
+pub use error::{Error, Result};
+pub use solution::{Answer, Outcome, Puzzle, Solution, Tool};
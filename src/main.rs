@@ -0,0 +1,497 @@
+use std::env;
+use std::path::PathBuf;
+use std::process;
+use std::str::FromStr;
+
+use aoc2021::answers::{Answers, Check};
+use aoc2021::bench::{self, Baseline, CountingAlloc, Report};
+use aoc2021::input::{Source, DEFAULT_NAME};
+use aoc2021::{diag, json, registry, Answer, Outcome, Puzzle};
+use colored::Colorize;
+
+#[global_allocator]
+static ALLOCATOR: CountingAlloc = CountingAlloc;
+
+const USAGE: &str = "Usage:
+    aoc list
+    aoc run --day <N> [--part <P>] [--input <FILE|DIR|->] [--name <NAME>]
+            [--answers <FILE>] [--format <text|json>] [--verbose]
+    aoc run --all [--input <DIR>] [--name <NAME>] [--answers <FILE>] [--format <text|json>]
+            [--verbose]
+    aoc bench (--day <N> | --all) [--runs <N>] [--input <FILE|DIR|->] [--name <NAME>]
+              [--save <FILE>] [--compare <FILE>] [--verbose]
+    aoc tool --day <N> [--input <FILE|DIR|->] [--name <NAME>] [--verbose] [<TOOL> [ARGS...]]
+
+The input defaults to the AOC_INPUT environment variable, then to the inputs bundled with
+the crate. A directory holds one day<N>/input.txt or day<N>.txt per day; '-' reads stdin.
+It can hold other named inputs, like the puzzle examples, as day<N>/<NAME>.txt or
+day<N>-<NAME>.txt, picked with '--name'. 'list' shows the inputs of each day.
+
+'run' checks the answers against the known ones, read from '--answers' or from the
+answers.toml of the input directory, and fails if any of them differs. With '--format json'
+it prints one {\"day\", \"input\", \"part\", \"answer\", \"duration\"} record per line, the
+duration of the part being in nanoseconds. '--verbose' prints the diagnostics of the solvers to stderr.
+
+'bench' runs parsing and each part N times (10 by default) and reports the min, median and
+max durations with the median number of allocations. '--save' writes the results to a
+baseline file that a later '--compare' reads back.
+
+'tool' runs one of the extra commands of a day on its input, passing it the arguments that
+follow its name. Without a tool name, it lists the tools of the day.";
+
+const DEFAULT_RUNS: usize = 10;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Format {
+    Text,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, ()> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(()),
+        }
+    }
+}
+
+struct RunOptions {
+    day: Option<u8>,
+    part: Option<u8>,
+    input: Source,
+    name: String,
+    answers: Option<PathBuf>,
+    format: Format,
+}
+
+struct BenchOptions {
+    day: Option<u8>,
+    input: Source,
+    name: String,
+    runs: usize,
+    save: Option<PathBuf>,
+    compare: Option<PathBuf>,
+}
+
+struct ToolOptions {
+    day: u8,
+    input: Source,
+    name: String,
+    tool: Option<String>,
+    args: Vec<String>,
+}
+
+enum Command {
+    List,
+    Run(RunOptions),
+    Bench(BenchOptions),
+    Tool(ToolOptions),
+}
+
+/// Parsed command line: the command, and whether the diagnostics of the solvers are printed,
+/// which every command but `list` accepts.
+struct Cli {
+    command: Command,
+    verbose: bool,
+}
+
+fn parse_value<T: FromStr>(name: &str, value: Option<&String>) -> Result<T, String> {
+    let value = value.ok_or_else(|| format!("missing value for '{}'", name))?;
+
+    value
+        .parse()
+        .map_err(|_| format!("invalid value '{}' for '{}'", value, name))
+}
+
+fn parse_args(args: &[String]) -> Result<Cli, String> {
+    let (command, options) = match args.split_first() {
+        Some((command, options)) => (command.as_str(), options),
+        None => return Err("missing command".to_string()),
+    };
+
+    let mut verbose = false;
+
+    let command = match command {
+        "list" => {
+            if let Some(arg) = options.first() {
+                return Err(format!("unexpected argument '{}'", arg));
+            }
+
+            Command::List
+        }
+        "run" | "bench" => {
+            let mut day: Option<u8> = None;
+            let mut part: Option<u8> = None;
+            let mut input: Option<String> = None;
+            let mut name = DEFAULT_NAME.to_string();
+            let mut all = false;
+            let mut runs = DEFAULT_RUNS;
+            let mut save: Option<PathBuf> = None;
+            let mut compare: Option<PathBuf> = None;
+            let mut answers: Option<PathBuf> = None;
+            let mut format = Format::Text;
+
+            let bench = command == "bench";
+
+            let mut it = options.iter();
+            while let Some(arg) = it.next() {
+                match arg.as_str() {
+                    "--day" => day = Some(parse_value(arg, it.next())?),
+                    "--part" if !bench => part = Some(parse_value(arg, it.next())?),
+                    "--input" => input = Some(parse_value(arg, it.next())?),
+                    "--name" => name = parse_value(arg, it.next())?,
+                    "--all" => all = true,
+                    "--runs" if bench => runs = parse_value(arg, it.next())?,
+                    "--save" if bench => save = Some(parse_value(arg, it.next())?),
+                    "--compare" if bench => compare = Some(parse_value(arg, it.next())?),
+                    "--answers" if !bench => answers = Some(parse_value(arg, it.next())?),
+                    "--format" if !bench => format = parse_value(arg, it.next())?,
+                    "--verbose" => verbose = true,
+                    _ => return Err(format!("unexpected argument '{}'", arg)),
+                }
+            }
+
+            let input = Source::resolve(input.as_deref());
+
+            match (day, all) {
+                (Some(_), true) => return Err("'--day' and '--all' are exclusive".to_string()),
+                (None, false) => return Err("expected '--day <N>' or '--all'".to_string()),
+                (None, true) if part.is_some() => {
+                    return Err("'--part' requires '--day'".to_string())
+                }
+                (None, true) if !input.is_dir() => {
+                    return Err("'--all' requires a directory of inputs".to_string())
+                }
+                _ => {}
+            }
+
+            if name != DEFAULT_NAME && !input.is_dir() {
+                return Err("'--name' requires a directory of inputs".to_string());
+            }
+
+            if runs == 0 {
+                return Err("'--runs' must be at least 1".to_string());
+            }
+
+            if bench {
+                Command::Bench(BenchOptions {
+                    day,
+                    input,
+                    name,
+                    runs,
+                    save,
+                    compare,
+                })
+            } else {
+                let answers = answers.or_else(|| Answers::path_for(&input));
+
+                Command::Run(RunOptions {
+                    day,
+                    part,
+                    input,
+                    name,
+                    answers,
+                    format,
+                })
+            }
+        }
+        "tool" => {
+            let mut day: Option<u8> = None;
+            let mut input: Option<String> = None;
+            let mut name = DEFAULT_NAME.to_string();
+
+            // Options come first, the arguments after the tool name belong to the tool
+            let mut it = options.iter();
+            let tool = loop {
+                let arg = match it.next() {
+                    Some(arg) => arg,
+                    None => break None,
+                };
+
+                match arg.as_str() {
+                    "--day" => day = Some(parse_value(arg, it.next())?),
+                    "--input" => input = Some(parse_value(arg, it.next())?),
+                    "--name" => name = parse_value(arg, it.next())?,
+                    "--verbose" => verbose = true,
+                    _ if arg.starts_with("--") => {
+                        return Err(format!("unexpected argument '{}'", arg))
+                    }
+                    _ => break Some(arg.clone()),
+                }
+            };
+
+            let day = day.ok_or_else(|| "expected '--day <N>'".to_string())?;
+            let input = Source::resolve(input.as_deref());
+            if name != DEFAULT_NAME && !input.is_dir() {
+                return Err("'--name' requires a directory of inputs".to_string());
+            }
+
+            Command::Tool(ToolOptions {
+                day,
+                input,
+                name,
+                tool,
+                args: it.cloned().collect(),
+            })
+        }
+        _ => return Err(format!("unknown command '{}'", command)),
+    };
+
+    Ok(Cli { command, verbose })
+}
+
+fn print_outcome(day: u8, name: &str, outcome: &Outcome, format: Format) {
+    let Outcome {
+        part,
+        answer,
+        duration,
+    } = outcome;
+
+    match format {
+        Format::Text => {
+            let answer = answer.to_string();
+
+            if answer.contains('\n') {
+                println!("Part {}:\n{}", part, answer);
+            } else {
+                println!("Part {}: {}", part, answer);
+            }
+        }
+        Format::Json => {
+            let answer = match answer {
+                Answer::Number(n) => n.to_string(),
+                Answer::Text(s) => json::string(s),
+            };
+
+            println!(
+                "{{\"day\": {}, \"input\": {}, \"part\": {}, \"answer\": {}, \"duration\": {}}}",
+                day,
+                json::string(name),
+                part,
+                answer,
+                duration.as_nanos()
+            );
+        }
+    }
+}
+
+/// Run a day and print its answers, returning how many of them differ from the known ones.
+fn run_day(puzzle: &dyn Puzzle, options: &RunOptions, known: &Answers) -> Result<usize, String> {
+    let input = read_input(puzzle, &options.input, &options.name)?;
+
+    let outcomes = puzzle
+        .run(&input, options.part)
+        .map_err(|e| format!("day {}: {}", puzzle.day(), e))?;
+    if let (Some(part), true) = (options.part, outcomes.is_empty()) {
+        return Err(format!("day {} has no part {}", puzzle.day(), part));
+    }
+
+    let mut mismatches = 0;
+
+    if options.format == Format::Text {
+        print_header(puzzle, &options.name);
+    }
+
+    for outcome in &outcomes {
+        print_outcome(puzzle.day(), &options.name, outcome, options.format);
+
+        let check = known.check(puzzle.day(), &options.name, outcome.part, &outcome.answer);
+        if let Check::Wrong { expected } = check {
+            let expected = expected.to_string();
+            let separator = if expected.contains('\n') { "\n" } else { " " };
+
+            // Keep stdout parsable in JSON
+            match options.format {
+                Format::Text => println!("{}{}{}", "Expected:".red(), separator, expected.red()),
+                Format::Json => eprintln!(
+                    "day {} part {}: expected{}{}",
+                    puzzle.day(),
+                    outcome.part,
+                    separator,
+                    expected
+                ),
+            }
+
+            mismatches += 1;
+        }
+    }
+
+    Ok(mismatches)
+}
+
+fn find_day(day: u8) -> Result<&'static dyn Puzzle, String> {
+    registry::find(day).ok_or_else(|| format!("day {} is not solved", day))
+}
+
+/// Puzzles to run: the given day, or every day having an input called `name`.
+fn select_days(
+    day: Option<u8>,
+    source: &Source,
+    name: &str,
+) -> Result<Vec<&'static dyn Puzzle>, String> {
+    match day {
+        Some(day) => Ok(vec![find_day(day)?]),
+        None => Ok(registry::DAYS
+            .iter()
+            .copied()
+            .filter(|p| source.names(p.day()).iter().any(|n| n == name))
+            .collect()),
+    }
+}
+
+fn read_input(puzzle: &dyn Puzzle, source: &Source, name: &str) -> Result<String, String> {
+    source
+        .read_named(puzzle.day(), name)
+        .map_err(|e| format!("failed to read input of day {}: {}", puzzle.day(), e))
+}
+
+fn print_header(puzzle: &dyn Puzzle, name: &str) {
+    if name == DEFAULT_NAME {
+        println!("Day {}: {}", puzzle.day(), puzzle.title());
+    } else {
+        println!("Day {}: {} ({})", puzzle.day(), puzzle.title(), name);
+    }
+}
+
+fn bench_day(
+    puzzle: &dyn Puzzle,
+    options: &BenchOptions,
+    baseline: Option<&Baseline>,
+) -> Result<Report, String> {
+    let input = read_input(puzzle, &options.input, &options.name)?;
+
+    let report = puzzle
+        .bench(&input, options.runs)
+        .map_err(|e| format!("day {}: {}", puzzle.day(), e))?;
+
+    print_header(puzzle, &options.name);
+    for (stage, stats) in &report.stages {
+        print!(
+            "    {}  min {:>9.2?}  median {:>9.2?}  max {:>9.2?}  allocations {:>8}",
+            stage, stats.min, stats.median, stats.max, stats.allocations
+        );
+
+        match baseline.and_then(|b| b.get(&(report.day, *stage))) {
+            Some(before) => println!(
+                "  (baseline {:.2?}, {:+.1}%)",
+                before.median,
+                bench::change(before, stats)
+            ),
+            None => println!(),
+        }
+    }
+
+    Ok(report)
+}
+
+fn bench(options: &BenchOptions) -> Result<(), String> {
+    let baseline = match &options.compare {
+        Some(path) => Some(bench::read_baseline(path).map_err(|e| e.to_string())?),
+        None => None,
+    };
+
+    let puzzles = select_days(options.day, &options.input, &options.name)?;
+
+    let mut reports = vec![];
+    for puzzle in puzzles {
+        reports.push(bench_day(puzzle, options, baseline.as_ref())?);
+    }
+
+    if let Some(path) = &options.save {
+        bench::write_baseline(path, &reports).map_err(|e| e.to_string())?;
+    }
+
+    Ok(())
+}
+
+fn tool(options: &ToolOptions) -> Result<(), String> {
+    let puzzle = find_day(options.day)?;
+
+    let name = match &options.tool {
+        Some(name) => name,
+        None => {
+            if puzzle.tools().is_empty() {
+                println!("Day {} has no tools", puzzle.day());
+            }
+            for tool in puzzle.tools() {
+                println!("{}  {}", tool.name, tool.usage);
+            }
+            return Ok(());
+        }
+    };
+
+    let tool = puzzle
+        .tool(name)
+        .ok_or_else(|| format!("day {} has no tool '{}'", puzzle.day(), name))?;
+    let input = read_input(puzzle, &options.input, &options.name)?;
+
+    let out = (tool.run)(&input, &options.args)
+        .map_err(|e| format!("day {} {}: {}", puzzle.day(), tool.name, e))?;
+    println!("{}", out);
+
+    Ok(())
+}
+
+fn run(command: Command) -> Result<(), String> {
+    match command {
+        Command::List => {
+            let source = Source::resolve(None);
+
+            for puzzle in registry::DAYS {
+                println!(
+                    "Day {:2}: {} [{}]",
+                    puzzle.day(),
+                    puzzle.title(),
+                    source.names(puzzle.day()).join(", ")
+                );
+            }
+        }
+        Command::Run(options) => {
+            let known = match &options.answers {
+                Some(path) => Answers::load(path).map_err(|e| e.to_string())?,
+                None => Answers::default(),
+            };
+
+            let puzzles = select_days(options.day, &options.input, &options.name)?;
+
+            let mut mismatches = 0;
+            for puzzle in puzzles {
+                mismatches += run_day(puzzle, &options, &known)?;
+            }
+
+            if mismatches > 0 {
+                return Err(format!(
+                    "{} answer(s) differ from the known ones",
+                    mismatches
+                ));
+            }
+        }
+        Command::Bench(options) => bench(&options)?,
+        Command::Tool(options) => tool(&options)?,
+    }
+
+    Ok(())
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let Cli { command, verbose } = match parse_args(&args) {
+        Ok(cli) => cli,
+        Err(e) => {
+            eprintln!("error: {}\n\n{}", e, USAGE);
+            process::exit(2);
+        }
+    };
+
+    diag::set_verbose(verbose);
+
+    if let Err(e) = run(command) {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    }
+}
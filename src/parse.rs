@@ -0,0 +1,156 @@
+use std::any;
+use std::str::FromStr;
+
+use crate::error::{Error, Result};
+
+/// One line of the puzzle input, remembering its number to locate errors.
+#[derive(Clone, Copy, Debug)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+/// Iterate over the lines of `input`, numbered from 1.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(idx, text)| Line {
+        number: idx + 1,
+        text,
+    })
+}
+
+impl<'a> Line<'a> {
+    /// Error located at byte `offset` of the line.
+    pub fn error_at(&self, offset: usize, message: impl Into<String>) -> Error {
+        let offset = offset.min(self.text.len());
+        let column = self.text[..offset].chars().count() + 1;
+
+        Error::at(self.number, column, message)
+    }
+
+    /// Error located at `part`, which must be a slice of the line. Any other slice points at
+    /// the start of the line.
+    pub fn error(&self, part: &str, message: impl Into<String>) -> Error {
+        let start = self.text.as_ptr() as usize;
+        let offset = (part.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|&o| o <= self.text.len())
+            .unwrap_or(0);
+
+        self.error_at(offset, message)
+    }
+
+    /// Parse `part`, a slice of the line, reporting its position on failure.
+    pub fn parse<T: FromStr>(&self, part: &'a str) -> Result<T> {
+        part.parse().map_err(|_| {
+            self.error(
+                part,
+                format!("expected {}, found '{}'", any::type_name::<T>(), part),
+            )
+        })
+    }
+
+    /// Split `part`, a slice of the line, around the first `separator`.
+    pub fn split_once(&self, part: &'a str, separator: &str) -> Result<(&'a str, &'a str)> {
+        part.split_once(separator)
+            .ok_or_else(|| self.error(part, format!("expected '{}'", separator)))
+    }
+
+    /// Remove `prefix` from `part`, a slice of the line.
+    pub fn strip_prefix(&self, part: &'a str, prefix: &str) -> Result<&'a str> {
+        part.strip_prefix(prefix)
+            .ok_or_else(|| self.error(part, format!("expected '{}'", prefix)))
+    }
+
+    /// Parse a `separator` separated list of values.
+    pub fn parse_list<T: FromStr>(&self, part: &'a str, separator: char) -> Result<Vec<T>> {
+        part.split(separator).map(|v| self.parse(v)).collect()
+    }
+}
+
+/// Parse a rectangular map of characters, converting each of them with `cell`. `expected`
+/// describes the valid characters in error messages.
+pub fn char_grid<T>(
+    input: &str,
+    expected: &str,
+    cell: impl Fn(char) -> Option<T>,
+) -> Result<Vec<Vec<T>>> {
+    grid_lines(lines(input), expected, cell)
+}
+
+/// Same as [`char_grid`], for a map that is only a section of the input.
+pub fn grid_lines<'a, T>(
+    lines: impl Iterator<Item = Line<'a>>,
+    expected: &str,
+    cell: impl Fn(char) -> Option<T>,
+) -> Result<Vec<Vec<T>>> {
+    let mut grid: Vec<Vec<T>> = vec![];
+
+    for line in lines {
+        let row = line
+            .text
+            .char_indices()
+            .map(|(offset, c)| {
+                cell(c).ok_or_else(|| {
+                    line.error_at(offset, format!("expected {}, found '{}'", expected, c))
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        if let Some(first) = grid.first() {
+            if row.len() != first.len() {
+                return Err(line.error_at(
+                    line.text.len(),
+                    format!("expected {} cells, found {}", first.len(), row.len()),
+                ));
+            }
+        }
+
+        grid.push(row);
+    }
+
+    if grid.is_empty() || grid[0].is_empty() {
+        return Err(Error::new("empty map"));
+    }
+
+    Ok(grid)
+}
+
+/// Parse a rectangular map of digits.
+pub fn digit_grid(input: &str) -> Result<Vec<Vec<u32>>> {
+    char_grid(input, "a digit", |c| c.to_digit(10))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_errors() {
+        let line = lines("1,2\n3,x").nth(1).unwrap();
+        let values: Vec<_> = line.text.split(',').collect();
+
+        assert_eq!(line.parse::<u32>(values[0]), Ok(3));
+        assert_eq!(
+            line.parse::<u32>(values[1]).unwrap_err().to_string(),
+            "line 2, column 3: expected u32, found 'x'"
+        );
+        assert_eq!(
+            line.split_once(line.text, " -> ").unwrap_err().to_string(),
+            "line 2, column 1: expected ' -> '"
+        );
+    }
+
+    #[test]
+    fn grid_errors() {
+        assert_eq!(digit_grid("12\n34"), Ok(vec![vec![1, 2], vec![3, 4]]));
+        assert_eq!(
+            digit_grid("12\n3a").unwrap_err().to_string(),
+            "line 2, column 2: expected a digit, found 'a'"
+        );
+        assert_eq!(
+            digit_grid("12\n345").unwrap_err().to_string(),
+            "line 2, column 4: expected 2 cells, found 3"
+        );
+        assert!(digit_grid("").is_err());
+    }
+}
@@ -0,0 +1,35 @@
+use crate::day1::Day1;
+use crate::day10::Day10;
+use crate::day11::Day11;
+use crate::day12::Day12;
+use crate::day13::Day13;
+use crate::day14::Day14;
+use crate::day15::Day15;
+use crate::day16::Day16;
+use crate::day17::Day17;
+use crate::day18::Day18;
+use crate::day19::Day19;
+use crate::day2::Day2;
+use crate::day20::Day20;
+use crate::day21::Day21;
+use crate::day22::Day22;
+use crate::day23::Day23;
+use crate::day24::Day24;
+use crate::day25::Day25;
+use crate::day3::Day3;
+use crate::day4::Day4;
+use crate::day5::Day5;
+use crate::day6::Day6;
+use crate::day7::Day7;
+use crate::day8::Day8;
+use crate::day9::Day9;
+use crate::solution::Puzzle;
+
+pub const DAYS: &[&dyn Puzzle] = &[
+    &Day1, &Day2, &Day3, &Day4, &Day5, &Day6, &Day7, &Day8, &Day9, &Day10, &Day11, &Day12, &Day13,
+    &Day14, &Day15, &Day16, &Day17, &Day18, &Day19, &Day20, &Day21, &Day22, &Day23, &Day24, &Day25,
+];
+
+pub fn find(day: u8) -> Option<&'static dyn Puzzle> {
+    DAYS.iter().copied().find(|p| p.day() == day)
+}
@@ -0,0 +1,160 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use crate::bench::{self, Report, Stage};
+use crate::error::Result;
+
+/// Answer to one part of a puzzle.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+macro_rules! impl_answer_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Number(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from_number!(i32, i64, i128, u32, u64, u128, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+/// Extra command of a day, to inspect a puzzle beyond its answers. It is run on the raw input
+/// with the arguments following its name on the command line, and returns the text to print.
+pub struct Tool {
+    pub name: &'static str,
+    /// Arguments and one line description, shown by `aoc tool --day <N>`.
+    pub usage: &'static str,
+    pub run: fn(input: &str, args: &[String]) -> Result<String>,
+}
+
+/// A day of the calendar.
+///
+/// The raw input is parsed once, then both parts are computed from the parsed form. Every puzzle
+/// has a first part, but not necessarily a second one (day 25 only has one puzzle).
+pub trait Solution {
+    const DAY: u8;
+    const TITLE: &'static str;
+
+    type Input;
+
+    /// Extra commands of the day.
+    const TOOLS: &'static [Tool] = &[];
+
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    fn part1(input: &Self::Input) -> Result<Answer>;
+
+    fn part2(_input: &Self::Input) -> Result<Option<Answer>> {
+        Ok(None)
+    }
+}
+
+/// Answer of one part, with the time taken to compute it from the parsed input.
+#[derive(Clone, Debug)]
+pub struct Outcome {
+    pub part: u8,
+    pub answer: Answer,
+    pub duration: Duration,
+}
+
+/// Object-safe view of a `Solution`, used to store every day in the same registry.
+pub trait Puzzle: Sync {
+    fn day(&self) -> u8;
+
+    fn title(&self) -> &'static str;
+
+    /// Parse `input` and compute the requested part, or every available part if `part` is
+    /// `None`.
+    fn run(&self, input: &str, part: Option<u8>) -> Result<Vec<Outcome>>;
+
+    /// Measure parsing and every available part over `runs` runs each.
+    fn bench(&self, input: &str, runs: usize) -> Result<Report>;
+
+    fn tools(&self) -> &'static [Tool];
+
+    fn tool(&self, name: &str) -> Option<&'static Tool> {
+        self.tools().iter().find(|t| t.name == name)
+    }
+}
+
+impl<S: Solution + Sync> Puzzle for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn tools(&self) -> &'static [Tool] {
+        S::TOOLS
+    }
+
+    fn run(&self, input: &str, part: Option<u8>) -> Result<Vec<Outcome>> {
+        let input = S::parse(input)?;
+
+        let mut out = vec![];
+
+        if part.unwrap_or(1) == 1 {
+            let start = Instant::now();
+            let answer = S::part1(&input)?;
+
+            out.push(Outcome {
+                part: 1,
+                answer,
+                duration: start.elapsed(),
+            });
+        }
+
+        if part.unwrap_or(2) == 2 {
+            let start = Instant::now();
+            if let Some(answer) = S::part2(&input)? {
+                out.push(Outcome {
+                    part: 2,
+                    answer,
+                    duration: start.elapsed(),
+                });
+            }
+        }
+
+        Ok(out)
+    }
+
+    fn bench(&self, input: &str, runs: usize) -> Result<Report> {
+        let (input, parse) = bench::measure(runs, || S::parse(input))?;
+        let (_, part1) = bench::measure(runs, || S::part1(&input))?;
+        let (answer, part2) = bench::measure(runs, || S::part2(&input))?;
+
+        let mut stages = vec![(Stage::Parse, parse), (Stage::Part1, part1)];
+        if answer.is_some() {
+            stages.push((Stage::Part2, part2));
+        }
+
+        Ok(Report {
+            day: S::DAY,
+            stages,
+        })
+    }
+}
@@ -0,0 +1,28 @@
+//! Helpers shared by the tests of several modules.
+
+/// Arguments of a tool, as read from the command line.
+pub fn args(args: &[&str]) -> Vec<String> {
+    args.iter().map(|a| a.to_string()).collect()
+}
+
+/// Pseudo random numbers from a linear congruential generator, to build reproducible inputs.
+pub struct Lcg(u64);
+
+impl Lcg {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    /// Next number, whose high bits are the most random ones.
+    pub fn next(&mut self) -> u64 {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        self.0
+    }
+
+    pub fn bool(&mut self) -> bool {
+        self.next() >> 63 == 1
+    }
+}
@@ -0,0 +1,251 @@
+//! Arguments of the day tools, and the frames written by the tools running a simulation.
+
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::error::{Error, Result};
+
+/// Arguments of a tool, read as options followed by their values.
+pub struct Options<'a> {
+    args: std::slice::Iter<'a, String>,
+}
+
+impl<'a> Options<'a> {
+    pub fn new(args: &'a [String]) -> Self {
+        Self { args: args.iter() }
+    }
+
+    /// Next option.
+    pub fn arg(&mut self) -> Option<&'a str> {
+        self.args.next().map(String::as_str)
+    }
+
+    /// Value following option `arg`.
+    pub fn value(&mut self, arg: &str) -> Result<&'a str> {
+        self.arg()
+            .ok_or_else(|| Error::new(format!("missing value for '{}'", arg)))
+    }
+
+    /// Value following option `arg`, parsed as a `T`.
+    pub fn parse<T: FromStr>(&mut self, arg: &str) -> Result<T> {
+        let value = self.value(arg)?;
+
+        value.parse().map_err(|_| invalid(arg, value))
+    }
+
+    /// Value following option `arg`, parsed as a number of at least 1.
+    pub fn positive<T: FromStr + From<u8> + PartialOrd>(&mut self, arg: &str) -> Result<T> {
+        let value = self.value(arg)?;
+
+        value
+            .parse()
+            .ok()
+            .filter(|n| *n >= T::from(1))
+            .ok_or_else(|| invalid(arg, value))
+    }
+}
+
+pub fn invalid(arg: &str, value: &str) -> Error {
+    Error::new(format!("invalid value '{}' for '{}'", value, arg))
+}
+
+pub fn unexpected(arg: &str) -> Error {
+    Error::new(format!("unexpected argument '{}'", arg))
+}
+
+/// Format of the frames: printed as text, or written to image files.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FrameFormat {
+    Text,
+    Pbm,
+    Ppm,
+    Png,
+}
+
+impl FrameFormat {
+    fn name(self) -> &'static str {
+        match self {
+            FrameFormat::Text => "text",
+            FrameFormat::Pbm => "pbm",
+            FrameFormat::Ppm => "ppm",
+            FrameFormat::Png => "png",
+        }
+    }
+
+    fn extension(self) -> &'static str {
+        match self {
+            FrameFormat::Text => "txt",
+            format => format.name(),
+        }
+    }
+}
+
+/// Frames of a simulation, printed or written to a directory as selected by the `--every`,
+/// `--format` and `--out` options.
+pub struct Frames {
+    /// Name of the steps of the simulation, labelling the frames and naming their files.
+    step_name: &'static str,
+    /// Image formats the frames can be written in, besides text.
+    formats: &'static [FrameFormat],
+    /// Steps between two frames.
+    pub every: usize,
+    pub format: FrameFormat,
+    dir: Option<PathBuf>,
+    out: String,
+    count: usize,
+}
+
+impl Frames {
+    pub fn new(step_name: &'static str, formats: &'static [FrameFormat]) -> Self {
+        Self {
+            step_name,
+            formats,
+            every: 1,
+            format: FrameFormat::Text,
+            dir: None,
+            out: String::new(),
+            count: 0,
+        }
+    }
+
+    /// Read the value of `arg` if it is one of the options of the frames, returning whether it
+    /// is one.
+    pub fn option(&mut self, arg: &str, options: &mut Options) -> Result<bool> {
+        match arg {
+            "--every" => self.every = options.positive(arg)?,
+            "--format" => {
+                let value = options.value(arg)?;
+                self.format = [FrameFormat::Text]
+                    .iter()
+                    .chain(self.formats)
+                    .copied()
+                    .find(|f| f.name() == value)
+                    .ok_or_else(|| invalid(arg, value))?;
+            }
+            "--out" => self.dir = Some(PathBuf::from(options.value(arg)?)),
+            _ => return Ok(false),
+        }
+
+        Ok(true)
+    }
+
+    /// Check the options once they are all read, and create the directory of the frames.
+    pub fn start(&self) -> Result<()> {
+        match &self.dir {
+            Some(dir) => {
+                fs::create_dir_all(dir).map_err(|e| Error::new(format!("{}: {}", dir.display(), e)))
+            }
+            None if self.format != FrameFormat::Text => Err(Error::new(
+                "images are written to the directory given by '--out'",
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// File of the frame of `step`, if the frames are written to a directory.
+    pub fn path(&self, step: usize) -> Option<PathBuf> {
+        let dir = self.dir.as_ref()?;
+
+        Some(dir.join(format!(
+            "{}-{:05}.{}",
+            self.step_name,
+            step,
+            self.format.extension()
+        )))
+    }
+
+    /// Print or write the frame of `step`, `image` encoding it in the image format.
+    pub fn write(
+        &mut self,
+        step: usize,
+        text: &dyn fmt::Display,
+        image: impl FnOnce(FrameFormat) -> Result<Vec<u8>>,
+    ) -> Result<()> {
+        match (self.format, self.path(step)) {
+            (FrameFormat::Text, None) => {
+                self.out += &format!("{} {}:\n{}\n\n", self.step_name, step, text)
+            }
+            (FrameFormat::Text, Some(path)) => write_file(&path, format!("{}\n", text).as_bytes())?,
+            (format, Some(path)) => write_file(&path, &image(format)?)?,
+            (_, None) => unreachable!("images need a directory"),
+        }
+
+        self.count += 1;
+        Ok(())
+    }
+
+    /// Frames printed as text, or the number of frames written and where.
+    pub fn finish(self) -> String {
+        match &self.dir {
+            Some(dir) => format!("wrote {} frame(s) to {}\n", self.count, dir.display()),
+            None => self.out,
+        }
+    }
+}
+
+fn write_file(path: &Path, data: &[u8]) -> Result<()> {
+    fs::write(path, data).map_err(|e| Error::new(format!("{}: {}", path.display(), e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::args;
+
+    #[test]
+    fn tool_args_options() {
+        let args = args(&["--rounds", "3", "--every", "0", "--seed"]);
+        let mut options = Options::new(&args);
+
+        assert_eq!(options.arg(), Some("--rounds"));
+        assert_eq!(options.parse::<usize>("--rounds"), Ok(3));
+        assert_eq!(options.arg(), Some("--every"));
+        assert_eq!(
+            options
+                .positive::<usize>("--every")
+                .unwrap_err()
+                .to_string(),
+            "invalid value '0' for '--every'"
+        );
+        assert_eq!(options.arg(), Some("--seed"));
+        assert_eq!(
+            options.value("--seed").unwrap_err().to_string(),
+            "missing value for '--seed'"
+        );
+        assert_eq!(options.arg(), None);
+    }
+
+    #[test]
+    fn tool_args_frames() {
+        let mut frames = Frames::new("step", &[FrameFormat::Ppm]);
+        let read = |frames: &mut Frames, a: &[&str]| {
+            let args = args(a);
+            let mut options = Options::new(&args);
+            let arg = options.arg().unwrap();
+            frames.option(arg, &mut options)
+        };
+
+        assert_eq!(read(&mut frames, &["--every", "2"]), Ok(true));
+        assert_eq!(read(&mut frames, &["--rounds", "2"]), Ok(false));
+        assert_eq!(
+            read(&mut frames, &["--format", "png"])
+                .unwrap_err()
+                .to_string(),
+            "invalid value 'png' for '--format'"
+        );
+        assert_eq!(read(&mut frames, &["--format", "ppm"]), Ok(true));
+        assert_eq!(
+            frames.start().unwrap_err().to_string(),
+            "images are written to the directory given by '--out'"
+        );
+
+        // Without a directory, text frames are printed
+        let mut frames = Frames::new("step", &[]);
+        frames.write(4, &"#.", |_| unreachable!()).unwrap();
+        assert_eq!(frames.every, 1);
+        assert_eq!(frames.path(4), None);
+        assert_eq!(frames.finish(), "step 4:\n#.\n\n");
+    }
+}